@@ -0,0 +1,508 @@
+//! Recipients the user saved and the recipients of their recent orders, kept
+//! in `localStorage` and shared through context.
+
+use leptos::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use web_sys::Storage;
+
+use crate::utils::address::{is_lookalike, is_valid_evm_address};
+
+/// `localStorage` entry holding the saved recipients.
+const STORAGE_KEY: &str = "untron.addressBook";
+
+/// `localStorage` entry holding the recipients of recent orders.
+const RECENT_STORAGE_KEY: &str = "untron.recentRecipients";
+
+/// Recent recipients remembered.
+const MAX_RECENT: usize = 10;
+
+/// Longest label accepted, in characters.
+const MAX_LABEL_CHARS: usize = 64;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedRecipient {
+    pub label: String,
+    pub address: String,
+    /// Chain id to send to by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain: Option<u32>,
+    /// Unix time (seconds) of the last order to this recipient.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_used: Option<u64>,
+}
+
+impl SavedRecipient {
+    pub fn new(label: &str, address: &str) -> Self {
+        Self {
+            label: label.trim().to_string(),
+            address: address.trim().to_string(),
+            chain: None,
+            last_used: None,
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        !self.label.is_empty()
+            && self.label.chars().count() <= MAX_LABEL_CHARS
+            && is_valid_evm_address(&self.address)
+    }
+
+    fn is_for(&self, address: &str) -> bool {
+        self.address.eq_ignore_ascii_case(address.trim())
+    }
+}
+
+/// Recipient of an order created in this browser.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentRecipient {
+    pub address: String,
+    pub chain: u32,
+    /// Amount sent, in units.
+    pub send_units: u64,
+    /// Unix time (seconds) the order was created.
+    pub at: u64,
+}
+
+/// Where a suggested recipient comes from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SuggestionSource {
+    /// The connected wallet's own address.
+    Wallet,
+    Saved {
+        label: String,
+    },
+    Recent,
+}
+
+/// A recipient offered while typing the destination address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecipientSuggestion {
+    pub address: String,
+    pub source: SuggestionSource,
+    /// Chain to send to, if known.
+    pub chain: Option<u32>,
+    /// Amount last sent to this address, in units.
+    pub send_units: Option<u64>,
+}
+
+/// A known recipient that another address imitates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lookalike {
+    pub address: String,
+    /// Label, if the recipient is saved.
+    pub label: Option<String>,
+}
+
+/// Why an address book file couldn't be imported.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ImportError {
+    /// Not a JSON list of recipients.
+    Malformed,
+    /// The entry at this index has no label or an invalid address.
+    InvalidEntry(usize),
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed => f.write_str("The file isn't an Untron address book."),
+            Self::InvalidEntry(index) => write!(
+                f,
+                "Entry {} has no label or an invalid address; nothing was imported.",
+                index + 1,
+            ),
+        }
+    }
+}
+
+/// The saved and recent recipients. Empty on the server and until the
+/// browser has loaded them, so hydration always starts from the server's
+/// markup.
+#[derive(Clone, Copy)]
+pub struct AddressBook {
+    entries: RwSignal<Vec<SavedRecipient>>,
+    /// Most recent first.
+    recent: RwSignal<Vec<RecentRecipient>>,
+}
+
+impl AddressBook {
+    /// Creates the book and loads the saved recipients once running in the
+    /// browser.
+    pub fn new() -> Self {
+        let entries = RwSignal::new(Vec::new());
+        let recent = RwSignal::new(Vec::new());
+        Effect::new(move |_| {
+            entries.set(load(STORAGE_KEY));
+            recent.set(load(RECENT_STORAGE_KEY));
+        });
+        Self { entries, recent }
+    }
+
+    pub fn entries(&self) -> Signal<Vec<SavedRecipient>> {
+        self.entries.into()
+    }
+
+    pub fn find(&self, address: &str) -> Option<SavedRecipient> {
+        self.entries
+            .with(|entries| entries.iter().find(|e| e.is_for(address)).cloned())
+    }
+
+    /// Adds `recipient`, or replaces the entry for the same address. Invalid
+    /// entries are ignored.
+    pub fn save(&self, recipient: SavedRecipient) {
+        if recipient.is_valid() {
+            self.update(|entries| upsert(entries, recipient));
+        }
+    }
+
+    /// Changes the entry for `address`, unless that would make it invalid.
+    pub fn edit(&self, address: &str, f: impl FnOnce(&mut SavedRecipient)) {
+        if let Some(mut entry) = self.find(address) {
+            f(&mut entry);
+            self.save(entry);
+        }
+    }
+
+    pub fn remove(&self, address: &str) {
+        self.update(|entries| entries.retain(|e| !e.is_for(address)));
+    }
+
+    /// A saved or recent recipient that `address` resembles without being
+    /// it, a sign of address poisoning. Saved addresses are trusted as is.
+    pub fn lookalike_of(&self, address: &str) -> Option<Lookalike> {
+        let recent = self.recent.read();
+        self.entries
+            .with(|entries| find_lookalike(address, entries, &recent))
+    }
+
+    /// Remembers an order to `recipient`, and when it was last used if saved.
+    pub fn record_order(&self, recipient: RecentRecipient) {
+        if self.find(&recipient.address).is_some() {
+            self.update(|entries| {
+                for entry in entries.iter_mut().filter(|e| e.is_for(&recipient.address)) {
+                    entry.last_used = Some(recipient.at);
+                }
+            });
+        }
+        self.recent.update(|recent| {
+            recent.retain(|r| !r.address.eq_ignore_ascii_case(&recipient.address));
+            recent.insert(0, recipient);
+            recent.truncate(MAX_RECENT);
+        });
+        self.recent
+            .with_untracked(|recent| store(RECENT_STORAGE_KEY, recent));
+    }
+
+    /// The book as pretty-printed JSON, for exporting.
+    pub fn export_json(&self) -> String {
+        self.entries
+            .with_untracked(serde_json::to_string_pretty)
+            .expect("address book serializes")
+    }
+
+    /// Merges the recipients in `json` into the book and returns how many
+    /// were read. Nothing is imported unless every entry is valid.
+    pub fn import_json(&self, json: &str) -> Result<usize, ImportError> {
+        let imported = parse_import(json)?;
+        let count = imported.len();
+        self.update(|entries| {
+            for recipient in imported {
+                upsert(entries, recipient);
+            }
+        });
+        Ok(count)
+    }
+
+    fn update(&self, f: impl FnOnce(&mut Vec<SavedRecipient>)) {
+        self.entries.update(f);
+        self.entries
+            .with_untracked(|entries| store(STORAGE_KEY, entries));
+    }
+}
+
+impl Default for AddressBook {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Up to `limit` recipients matching `query`: the connected wallet's address,
+/// then saved recipients, then recent ones.
+pub fn recipient_suggestions(
+    wallet_address: Option<&str>,
+    book: Option<AddressBook>,
+    query: &str,
+    limit: usize,
+) -> Vec<RecipientSuggestion> {
+    let (saved, recent) = book.map_or_else(Default::default, |book| {
+        (book.entries.get(), book.recent.get())
+    });
+    merge_suggestions(wallet_address, &saved, &recent, query, limit)
+}
+
+/// Address book provided by `App`, if any; embedded widgets don't keep one.
+pub fn use_address_book() -> Option<AddressBook> {
+    use_context::<AddressBook>()
+}
+
+// ---- List operations --------------------------------------------------------
+
+fn upsert(entries: &mut Vec<SavedRecipient>, recipient: SavedRecipient) {
+    match entries.iter_mut().find(|e| e.is_for(&recipient.address)) {
+        Some(entry) => {
+            let last_used = entry.last_used.max(recipient.last_used);
+            *entry = SavedRecipient {
+                last_used,
+                ..recipient
+            };
+        }
+        None => entries.push(recipient),
+    }
+}
+
+/// Up to `limit` entries whose label or address contains `query`, most
+/// recently used first.
+fn suggestions(entries: &[SavedRecipient], query: &str, limit: usize) -> Vec<SavedRecipient> {
+    let query = query.trim().to_lowercase();
+    let mut matches = entries
+        .iter()
+        .filter(|e| {
+            e.label.to_lowercase().contains(&query) || e.address.to_lowercase().contains(&query)
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    // Never-used entries last; otherwise the most recent first.
+    matches.sort_by_key(|e| std::cmp::Reverse(e.last_used));
+    matches.truncate(limit);
+    matches
+}
+
+fn merge_suggestions(
+    wallet_address: Option<&str>,
+    saved: &[SavedRecipient],
+    recent: &[RecentRecipient],
+    query: &str,
+    limit: usize,
+) -> Vec<RecipientSuggestion> {
+    let last_order = |address: &str| {
+        recent
+            .iter()
+            .find(|r| r.address.eq_ignore_ascii_case(address))
+    };
+    let query = query.trim().to_lowercase();
+
+    let wallet = wallet_address
+        .filter(|address| is_valid_evm_address(address))
+        .map(|address| RecipientSuggestion {
+            address: address.to_string(),
+            source: SuggestionSource::Wallet,
+            chain: None,
+            send_units: last_order(address).map(|r| r.send_units),
+        });
+    let saved = suggestions(saved, &query, saved.len())
+        .into_iter()
+        .map(|e| RecipientSuggestion {
+            send_units: last_order(&e.address).map(|r| r.send_units),
+            source: SuggestionSource::Saved { label: e.label },
+            address: e.address,
+            chain: e.chain,
+        });
+    let recent = recent.iter().map(|r| RecipientSuggestion {
+        address: r.address.clone(),
+        source: SuggestionSource::Recent,
+        chain: Some(r.chain),
+        send_units: Some(r.send_units),
+    });
+
+    let mut merged = Vec::<RecipientSuggestion>::new();
+    for suggestion in wallet.into_iter().chain(saved).chain(recent) {
+        let is_match = match &suggestion.source {
+            // Saved entries were matched against their label, too.
+            SuggestionSource::Saved { .. } => true,
+            _ => suggestion.address.to_lowercase().contains(&query),
+        };
+        let is_new = !merged
+            .iter()
+            .any(|s| s.address.eq_ignore_ascii_case(&suggestion.address));
+        if is_match && is_new {
+            merged.push(suggestion);
+        }
+    }
+    merged.truncate(limit);
+    merged
+}
+
+fn find_lookalike(
+    address: &str,
+    saved: &[SavedRecipient],
+    recent: &[RecentRecipient],
+) -> Option<Lookalike> {
+    if saved.iter().any(|e| e.is_for(address)) {
+        return None;
+    }
+    let saved = saved.iter().map(|e| (&e.address, Some(&e.label)));
+    let recent = recent.iter().map(|r| (&r.address, None));
+    saved
+        .chain(recent)
+        .find(|(known, _)| is_lookalike(address, known))
+        .map(|(known, label)| Lookalike {
+            address: known.clone(),
+            label: label.cloned(),
+        })
+}
+
+fn parse_import(json: &str) -> Result<Vec<SavedRecipient>, ImportError> {
+    let entries = serde_json::from_str::<Vec<SavedRecipient>>(json)
+        .map_err(|_| ImportError::Malformed)?
+        .into_iter()
+        .map(|e| SavedRecipient {
+            label: e.label.trim().to_string(),
+            address: e.address.trim().to_string(),
+            ..e
+        })
+        .collect::<Vec<_>>();
+    match entries.iter().position(|e| !e.is_valid()) {
+        Some(index) => Err(ImportError::InvalidEntry(index)),
+        None => Ok(entries),
+    }
+}
+
+// ---- Storage ----------------------------------------------------------------
+
+fn local_storage() -> Option<Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+fn load<T: DeserializeOwned>(key: &str) -> Vec<T> {
+    local_storage()
+        .and_then(|s| s.get_item(key).ok().flatten())
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+fn store<T: Serialize>(key: &str, items: &[T]) {
+    if let (Some(storage), Ok(raw)) = (local_storage(), serde_json::to_string(items)) {
+        let _ = storage.set_item(key, &raw);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: &str = "0x1111111111111111111111111111111111111111";
+    const BOB: &str = "0x2222222222222222222222222222222222222222";
+
+    fn recipient(label: &str, address: &str, last_used: Option<u64>) -> SavedRecipient {
+        SavedRecipient {
+            last_used,
+            ..SavedRecipient::new(label, address)
+        }
+    }
+
+    #[test]
+    fn upsert_replaces_entries_for_the_same_address() {
+        let mut entries = vec![recipient("Alice", ALICE, Some(10))];
+        upsert(&mut entries, recipient("Alice (work)", ALICE, None));
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].label, "Alice (work)");
+        assert_eq!(entries[0].last_used, Some(10));
+    }
+
+    #[test]
+    fn suggestions_match_label_or_address_most_recent_first() {
+        let entries = [
+            recipient("Alice", ALICE, None),
+            recipient("Bob", BOB, Some(5)),
+        ];
+        let labels = |query| {
+            suggestions(&entries, query, 5)
+                .into_iter()
+                .map(|e| e.label)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(labels(""), ["Bob", "Alice"]);
+        assert_eq!(labels("ali"), ["Alice"]);
+        assert_eq!(labels("0x2222"), ["Bob"]);
+    }
+
+    #[test]
+    fn suggestions_put_the_wallet_first_and_skip_duplicates() {
+        const CAROL: &str = "0x3333333333333333333333333333333333333333";
+        let saved = [recipient("Bob", BOB, Some(5))];
+        let recent = [BOB, CAROL].map(|address| RecentRecipient {
+            address: address.to_string(),
+            chain: 42161,
+            send_units: 7,
+            at: 5,
+        });
+        let addresses = |query| {
+            merge_suggestions(Some(ALICE), &saved, &recent, query, 5)
+                .into_iter()
+                .map(|s| (s.address, s.send_units))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            addresses(""),
+            [
+                (ALICE.to_string(), None),
+                (BOB.to_string(), Some(7)),
+                (CAROL.to_string(), Some(7)),
+            ]
+        );
+        assert_eq!(addresses("bob"), [(BOB.to_string(), Some(7))]);
+        assert_eq!(addresses("0x33"), [(CAROL.to_string(), Some(7))]);
+    }
+
+    #[test]
+    fn flags_lookalikes_of_saved_and_recent_recipients() {
+        let saved = [recipient("Alice", ALICE, None)];
+        let recent = [RecentRecipient {
+            address: BOB.to_string(),
+            chain: 42161,
+            send_units: 1,
+            at: 1,
+        }];
+        let poisoned = |known: &str| format!("{}{}{}", &known[..6], "0".repeat(32), &known[38..]);
+
+        assert_eq!(
+            find_lookalike(&poisoned(ALICE), &saved, &recent),
+            Some(Lookalike {
+                address: ALICE.to_string(),
+                label: Some("Alice".to_string()),
+            })
+        );
+        assert_eq!(
+            find_lookalike(&poisoned(BOB), &saved, &recent).map(|l| l.label),
+            Some(None)
+        );
+        assert_eq!(find_lookalike(ALICE, &saved, &recent), None);
+        // Saving an address vouches for it.
+        let saved = [recipient("Bob?", &poisoned(BOB), None)];
+        assert_eq!(find_lookalike(&poisoned(BOB), &saved, &recent), None);
+    }
+
+    #[test]
+    fn import_rejects_invalid_files() {
+        assert_eq!(parse_import("{}"), Err(ImportError::Malformed));
+        assert_eq!(
+            parse_import(&format!(
+                r#"[{{"label":"Alice","address":"{ALICE}"}},{{"label":" ","address":"{BOB}"}}]"#
+            )),
+            Err(ImportError::InvalidEntry(1))
+        );
+        assert_eq!(
+            parse_import(&format!(
+                r#"[{{"label":" Alice ","address":"{ALICE}","chain":8453}}]"#
+            )),
+            Ok(vec![SavedRecipient {
+                chain: Some(8453),
+                ..SavedRecipient::new("Alice", ALICE)
+            }])
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use serde::Deserialize;
+
+/// Failure kinds surfaced to the UI.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ApiError {
+    /// The request didn't complete within its timeout.
+    Timeout,
+    /// The request was aborted, usually because its component was unmounted.
+    Cancelled,
+    /// The request never got a response (offline, DNS, CORS…).
+    Network(String),
+    /// The server answered with a non-2xx status. `error` is the decoded body
+    /// when it follows the API error format.
+    Http {
+        status: u16,
+        body: String,
+        error: Option<ServerError>,
+    },
+    /// The response body couldn't be decoded.
+    Decode(String),
+}
+
+impl ApiError {
+    /// Builds an [`ApiError::Http`], decoding the body if possible.
+    pub fn http(status: u16, body: String) -> Self {
+        let error = ServerError::decode(&body);
+        Self::Http {
+            status,
+            body,
+            error,
+        }
+    }
+
+    /// Whether repeating the same request may succeed.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Timeout | Self::Network(_) => true,
+            Self::Http { status, .. } => matches!(*status, 408 | 429) || *status >= 500,
+            Self::Cancelled | Self::Decode(_) => false,
+        }
+    }
+
+    /// The decoded server error, if any.
+    pub fn server_error(&self) -> Option<&ServerError> {
+        match self {
+            Self::Http { error, .. } => error.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Error body returned by the API, either bare or wrapped in `{"error": …}`.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct ServerError {
+    pub code: ErrorCode,
+    /// Human-readable English message from the backend.
+    #[serde(default)]
+    pub message: String,
+    /// Request field the error refers to, if any.
+    #[serde(default)]
+    pub field: Option<ErrorField>,
+}
+
+impl ServerError {
+    fn decode(body: &str) -> Option<Self> {
+        #[derive(Deserialize)]
+        struct Envelope {
+            error: ServerError,
+        }
+
+        serde_json::from_str::<Envelope>(body)
+            .map(|envelope| envelope.error)
+            .or_else(|_| serde_json::from_str::<ServerError>(body))
+            .ok()
+    }
+}
+
+/// Machine-readable error codes known to the frontend.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    AmountTooLow,
+    AmountTooHigh,
+    InsufficientLiquidity,
+    InvalidBeneficiary,
+    UnsupportedChain,
+    RateChanged,
+    RateLimited,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Request fields an error can point at. Names match `CreateOrderRequest`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ErrorField {
+    FromAmount,
+    Beneficiary,
+    ToChain,
+    Rate,
+    #[serde(other)]
+    Other,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_wrapped_and_bare_errors() {
+        let expected = ServerError {
+            code: ErrorCode::AmountTooLow,
+            message: "too low".into(),
+            field: Some(ErrorField::FromAmount),
+        };
+        let wrapped =
+            r#"{"error": {"code": "AMOUNT_TOO_LOW", "message": "too low", "field": "fromAmount"}}"#;
+        let bare = r#"{"code": "AMOUNT_TOO_LOW", "message": "too low", "field": "fromAmount"}"#;
+        assert_eq!(ServerError::decode(wrapped), Some(expected.clone()));
+        assert_eq!(ServerError::decode(bare), Some(expected));
+    }
+
+    #[test]
+    fn unknown_codes_and_fields_fall_back() {
+        let error = ServerError::decode(r#"{"code": "SOMETHING_NEW", "field": "memo"}"#).unwrap();
+        assert_eq!(error.code, ErrorCode::Unknown);
+        assert_eq!(error.field, Some(ErrorField::Other));
+        assert_eq!(error.message, "");
+    }
+
+    #[test]
+    fn non_json_bodies_keep_the_status() {
+        for body in [
+            "",
+            "Bad Gateway",
+            "<html>502</html>",
+            r#"{"message": "no code"}"#,
+        ] {
+            let err = ApiError::http(502, body.to_string());
+            assert_eq!(err.server_error(), None, "{body:?}");
+            assert!(matches!(err, ApiError::Http { status: 502, .. }));
+        }
+    }
+
+    #[test]
+    fn retries_only_what_may_succeed() {
+        for status in [408, 429, 500, 502, 503] {
+            assert!(
+                ApiError::http(status, String::new()).is_retryable(),
+                "{status}"
+            );
+        }
+        for status in [400, 401, 404, 409, 422] {
+            assert!(
+                !ApiError::http(status, String::new()).is_retryable(),
+                "{status}"
+            );
+        }
+        assert!(ApiError::Timeout.is_retryable());
+        assert!(ApiError::Network("offline".into()).is_retryable());
+        assert!(!ApiError::Cancelled.is_retryable());
+        assert!(!ApiError::Decode("eof".into()).is_retryable());
+    }
+}
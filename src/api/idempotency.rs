@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use web_sys::Storage;
+
+/// `sessionStorage` entry holding the keys of unfinished submissions, by slot.
+pub(crate) const STORAGE_KEY: &str = "untron.pendingSubmissions";
+
+/// A submitted request body together with the key it was first sent with.
+#[derive(Serialize, Deserialize)]
+struct PendingSubmission {
+    key: String,
+    body: String,
+}
+
+/// Returns the idempotency key for a submission with the given body.
+///
+/// `slot` names what is being submitted (the swap form, one row of a batch)
+/// so that independent submissions don't overwrite each other's keys.
+/// Submitting the same body in the same slot again (a retry, a second click,
+/// or a resubmit after a reload) reuses the stored key so the backend can
+/// deduplicate it; any other body starts a new submission with a fresh key.
+pub fn key_for(slot: &str, body: &str) -> String {
+    let storage = session_storage();
+    let mut pending = load(storage.as_ref());
+    if let Some(submission) = pending.get(slot).filter(|p| p.body == body) {
+        return submission.key.clone();
+    }
+
+    let key = new_key();
+    pending.insert(
+        slot.to_string(),
+        PendingSubmission {
+            key: key.clone(),
+            body: body.to_string(),
+        },
+    );
+    store(storage.as_ref(), &pending);
+    key
+}
+
+/// Forgets the pending submission in `slot` once its outcome is known.
+pub fn clear(slot: &str) {
+    let storage = session_storage();
+    let mut pending = load(storage.as_ref());
+    if pending.remove(slot).is_some() {
+        store(storage.as_ref(), &pending);
+    }
+}
+
+fn load(storage: Option<&Storage>) -> BTreeMap<String, PendingSubmission> {
+    storage
+        .and_then(|s| s.get_item(STORAGE_KEY).ok().flatten())
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+fn store(storage: Option<&Storage>, pending: &BTreeMap<String, PendingSubmission>) {
+    if let (Some(storage), Ok(raw)) = (storage, serde_json::to_string(pending)) {
+        let _ = if pending.is_empty() {
+            storage.remove_item(STORAGE_KEY)
+        } else {
+            storage.set_item(STORAGE_KEY, &raw)
+        };
+    }
+}
+
+fn session_storage() -> Option<Storage> {
+    web_sys::window()?.session_storage().ok().flatten()
+}
+
+/// A fresh random id (a UUID where supported). Browser only.
+pub fn new_key() -> String {
+    web_sys::window()
+        .and_then(|w| w.crypto().ok())
+        .map(|crypto| crypto.random_uuid())
+        .unwrap_or_else(|| {
+            // Very old browsers lack `crypto.randomUUID`; uniqueness per tab is enough.
+            format!(
+                "{:x}-{:x}",
+                js_sys::Date::now() as u64,
+                (js_sys::Math::random() * u32::MAX as f64) as u32
+            )
+        })
+}
@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+
+use super::error::ApiError;
+use super::request::{ApiRequest, Cancellation};
+
+#[derive(Deserialize)]
+struct ApiInfoResponse {
+    #[serde(rename = "availableLiquidity")]
+    available_liquidity: String,
+    #[serde(rename = "minOrderOutput", default)]
+    min_order_output: Option<String>,
+}
+
+/// Parsed `/info` response.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Info {
+    /// Maximum amount (in units) that can be received by a single order.
+    pub available_liquidity: u64,
+    /// Minimum amount (in units) a single order must receive; 0 if unrestricted.
+    pub min_order_output: u64,
+}
+
+/// Loading state of `/info` as seen by the UI.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InfoState {
+    /// The first request is still in flight.
+    Loading,
+    /// The last request failed (after retries).
+    Unavailable(ApiError),
+    /// The last request succeeded.
+    Ready(Info),
+}
+
+impl InfoState {
+    /// Builds the state from a `LocalResource` value (`None` while loading).
+    pub fn from_resource(value: Option<Result<Info, ApiError>>) -> Self {
+        match value {
+            None => Self::Loading,
+            Some(Ok(info)) => Self::Ready(info),
+            Some(Err(err)) => Self::Unavailable(err),
+        }
+    }
+
+    /// Available liquidity in units, if known.
+    pub fn max_units(&self) -> Option<u64> {
+        match self {
+            Self::Ready(info) => Some(info.available_liquidity),
+            _ => None,
+        }
+    }
+
+    /// Minimum order output in units, if known.
+    pub fn min_units(&self) -> Option<u64> {
+        match self {
+            Self::Ready(info) => Some(info.min_order_output),
+            _ => None,
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        matches!(self, Self::Ready(_))
+    }
+}
+
+/// Fetches `/info` from the backend at `api_base_url`.
+pub async fn fetch_info(
+    api_base_url: String,
+    cancellation: Cancellation,
+) -> Result<Info, ApiError> {
+    ApiRequest::get(format!("{api_base_url}/info"))
+        .cancel_with(&cancellation)
+        .send_json::<ApiInfoResponse>()
+        .await?
+        .parse()
+}
+
+/// Result of [`prefetch_info`], provided to the page it was fetched for.
+#[derive(Clone, Debug, Default)]
+pub struct PrefetchedInfo(pub Option<Info>);
+
+/// `/info` as rendered into the page by the server, so liquidity is known
+/// before the first client request. Always `None` in the browser, and when
+/// `enabled` is false or the backend doesn't answer quickly.
+pub async fn prefetch_info(api_base_url: String, enabled: bool) -> Option<Info> {
+    #[cfg(feature = "ssr")]
+    if enabled {
+        return server::fetch_info(&api_base_url)
+            .await
+            .inspect_err(|err| leptos::logging::warn!("prefetching /info failed: {err:?}"))
+            .ok();
+    }
+    let _ = (api_base_url, enabled);
+    None
+}
+
+impl ApiInfoResponse {
+    fn parse(self) -> Result<Info, ApiError> {
+        let parse_units = |raw: &str| {
+            raw.parse::<u128>()
+                .map(|units| units.min(u128::from(u64::MAX)) as u64)
+                .map_err(|err| ApiError::Decode(err.to_string()))
+        };
+
+        Ok(Info {
+            available_liquidity: parse_units(&self.available_liquidity)?,
+            min_order_output: self
+                .min_order_output
+                .as_deref()
+                .map(parse_units)
+                .transpose()?
+                .unwrap_or(0),
+        })
+    }
+}
+
+#[cfg(feature = "ssr")]
+mod server {
+    use std::time::Duration;
+
+    use super::{ApiInfoResponse, Info};
+    use crate::api::ApiError;
+
+    /// Rendering waits for the prefetch, so it has to give up early.
+    const PREFETCH_TIMEOUT: Duration = Duration::from_secs(2);
+
+    pub async fn fetch_info(api_base_url: &str) -> Result<Info, ApiError> {
+        let network = |err: reqwest::Error| {
+            if err.is_timeout() {
+                ApiError::Timeout
+            } else {
+                ApiError::Network(err.to_string())
+            }
+        };
+        let response = reqwest::Client::new()
+            .get(format!("{api_base_url}/info"))
+            .timeout(PREFETCH_TIMEOUT)
+            .send()
+            .await
+            .map_err(network)?;
+        let status = response.status();
+        let body = response.text().await.map_err(network)?;
+        if !status.is_success() {
+            return Err(ApiError::http(status.as_u16(), body));
+        }
+        serde_json::from_str::<ApiInfoResponse>(&body)
+            .map_err(|err| ApiError::Decode(err.to_string()))?
+            .parse()
+    }
+}
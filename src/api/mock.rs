@@ -0,0 +1,397 @@
+//! In-process stand-in for the Untron API, compiled in with the `mock-api`
+//! feature so the UI can be worked on and tested without network access.
+//!
+//! [`ApiRequest`](super::request::ApiRequest) hands every request for
+//! `/info`, `/create-order` or `/order/{id}` to [`respond`] instead of the
+//! network, whatever the configured base URL. Anything else (config and
+//! content files) is still fetched for real.
+//!
+//! The behaviour is scripted with the `mock` query parameter, e.g.
+//! `/?mock=info:500,create-order:RATE_CHANGED,lifecycle:expire`:
+//! - `<endpoint>:<failure>` makes every request to `info`, `create-order` or
+//!   `order` fail with `timeout`, `network`, an HTTP status, or an API error
+//!   code (answered with a 400 or, for `RATE_LIMITED`, a 429),
+//! - `lifecycle:<complete|expire|fail>` picks how new orders end.
+//!
+//! The script is kept in `sessionStorage`, so it survives navigation.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_json::json;
+
+use super::error::ApiError;
+use super::order::{Order, OrderStatus};
+use super::request::sleep;
+
+/// `sessionStorage` entry holding the active script.
+const STORAGE_KEY: &str = "untron.mockScript";
+
+/// Simulated round-trip time, long enough to see loading states.
+const LATENCY: Duration = Duration::from_millis(300);
+
+/// 250,000 USDT.
+const AVAILABLE_LIQUIDITY: u64 = 250_000_000_000;
+/// 1 USDT.
+const MIN_ORDER_OUTPUT: u64 = 1_000_000;
+
+const DEPOSIT_ADDRESS: &str = "TMockDepositAddress1111111111111111";
+
+/// Time a mocked order spends in each non-final status.
+const STEP_MS: f64 = 5_000.0;
+
+// ---- Script ---------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endpoint {
+    Info,
+    CreateOrder,
+    Order,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Failure {
+    Timeout,
+    Network,
+    Status(u16),
+    /// An API error code such as `AMOUNT_TOO_LOW`.
+    Code(String),
+}
+
+/// How mocked orders end.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Lifecycle {
+    #[default]
+    Complete,
+    Expire,
+    Fail,
+}
+
+impl Lifecycle {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Complete => "complete",
+            Self::Expire => "expire",
+            Self::Fail => "fail",
+        }
+    }
+
+    fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "complete" => Some(Self::Complete),
+            "expire" => Some(Self::Expire),
+            "fail" => Some(Self::Fail),
+            _ => None,
+        }
+    }
+
+    /// Status of an order `elapsed_ms` after it was created.
+    fn status_at(self, elapsed_ms: f64) -> OrderStatus {
+        match (self, elapsed_ms / STEP_MS) {
+            (_, step) if step < 1.0 => OrderStatus::AwaitingDeposit,
+            (Self::Expire, _) => OrderStatus::Expired,
+            (_, step) if step < 2.0 => OrderStatus::Processing,
+            (Self::Complete, _) => OrderStatus::Completed,
+            (Self::Fail, _) => OrderStatus::Failed,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MockScript {
+    pub failures: Vec<(Endpoint, Failure)>,
+    pub lifecycle: Lifecycle,
+}
+
+impl MockScript {
+    /// Parses the `mock` query parameter format described in the module docs.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut script = Self::default();
+        for rule in spec.split(',').map(str::trim).filter(|r| !r.is_empty()) {
+            let (target, value) = rule
+                .split_once(':')
+                .ok_or_else(|| format!("{rule:?} isn't <target>:<value>"))?;
+            let endpoint = match target {
+                "lifecycle" => {
+                    script.lifecycle = Lifecycle::parse(value)
+                        .ok_or_else(|| format!("unknown lifecycle {value:?}"))?;
+                    continue;
+                }
+                "info" => Endpoint::Info,
+                "create-order" => Endpoint::CreateOrder,
+                "order" => Endpoint::Order,
+                _ => return Err(format!("unknown endpoint {target:?}")),
+            };
+            let failure = match value {
+                "timeout" => Failure::Timeout,
+                "network" => Failure::Network,
+                _ => match value.parse() {
+                    Ok(status) => Failure::Status(status),
+                    Err(_) if value.chars().all(|c| c.is_ascii_uppercase() || c == '_') => {
+                        Failure::Code(value.to_string())
+                    }
+                    Err(_) => return Err(format!("unknown failure {value:?}")),
+                },
+            };
+            script.failures.push((endpoint, failure));
+        }
+        Ok(script)
+    }
+
+    fn failure_for(&self, endpoint: Endpoint) -> Option<&Failure> {
+        self.failures
+            .iter()
+            .find(|(e, _)| *e == endpoint)
+            .map(|(_, failure)| failure)
+    }
+}
+
+// ---- State ----------------------------------------------------------------
+
+#[derive(Default)]
+struct State {
+    script: MockScript,
+    /// Order ids by idempotency key, to answer replays with a 409.
+    orders_by_key: HashMap<String, String>,
+    /// Requests answered so far, for tests to inspect.
+    #[cfg(all(test, target_arch = "wasm32"))]
+    requests: Vec<(Endpoint, Option<String>)>,
+}
+
+thread_local! {
+    static STATE: RefCell<State> = RefCell::new(State {
+        script: load_script(),
+        ..State::default()
+    });
+}
+
+/// Reads the script from the `mock` query parameter, remembering it for the
+/// rest of the session, or from an earlier page of the session.
+fn load_script() -> MockScript {
+    let window = web_sys::window().expect("mock API runs in a browser");
+    let storage = window.session_storage().ok().flatten();
+    let from_query = window
+        .location()
+        .search()
+        .ok()
+        .and_then(|search| web_sys::UrlSearchParams::new_with_str(&search).ok())
+        .and_then(|params| params.get("mock"));
+    if let (Some(spec), Some(storage)) = (&from_query, &storage) {
+        let _ = storage.set_item(STORAGE_KEY, spec);
+    }
+    let spec = from_query
+        .or_else(|| storage.and_then(|s| s.get_item(STORAGE_KEY).ok().flatten()))
+        .unwrap_or_default();
+    MockScript::parse(&spec).unwrap_or_else(|err| {
+        leptos::logging::warn!("ignoring mock script {spec:?}: {err}");
+        MockScript::default()
+    })
+}
+
+/// Replaces the script and forgets everything seen so far.
+#[cfg(all(test, target_arch = "wasm32"))]
+pub fn reset(script: MockScript) {
+    STATE.with_borrow_mut(|state| {
+        *state = State {
+            script,
+            ..State::default()
+        }
+    });
+}
+
+/// Requests answered since the last call, with their bodies.
+#[cfg(all(test, target_arch = "wasm32"))]
+pub fn take_requests() -> Vec<(Endpoint, Option<String>)> {
+    STATE.with_borrow_mut(|state| std::mem::take(&mut state.requests))
+}
+
+// ---- Requests -------------------------------------------------------------
+
+/// Which mocked endpoint `url` points at, with the order id for `/order/{id}`.
+fn route(url: &str) -> Option<(Endpoint, Option<&str>)> {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    if path.ends_with("/info") {
+        Some((Endpoint::Info, None))
+    } else if path.ends_with("/create-order") {
+        Some((Endpoint::CreateOrder, None))
+    } else {
+        let (_, id) = path.rsplit_once("/order/")?;
+        Some((Endpoint::Order, Some(id)))
+    }
+}
+
+/// Answers a request the way the backend would, or returns `None` when `url`
+/// isn't an API endpoint the mock knows.
+pub(super) async fn respond(
+    url: &str,
+    headers: &[(&'static str, String)],
+    body: Option<&str>,
+) -> Option<Result<String, ApiError>> {
+    let (endpoint, id) = route(url)?;
+    #[cfg(all(test, target_arch = "wasm32"))]
+    STATE.with_borrow_mut(|state| state.requests.push((endpoint, body.map(str::to_string))));
+    sleep(LATENCY).await;
+
+    let failure = STATE.with_borrow(|state| state.script.failure_for(endpoint).cloned());
+    if let Some(failure) = failure {
+        return Some(Err(match failure {
+            Failure::Timeout => ApiError::Timeout,
+            Failure::Network => ApiError::Network("mocked network failure".to_string()),
+            Failure::Status(status) => ApiError::http(status, String::new()),
+            Failure::Code(code) => {
+                let status = if code == "RATE_LIMITED" { 429 } else { 400 };
+                ApiError::http(status, error_body(&code))
+            }
+        }));
+    }
+
+    Some(match endpoint {
+        Endpoint::Info => Ok(json!({
+            "availableLiquidity": AVAILABLE_LIQUIDITY.to_string(),
+            "minOrderOutput": MIN_ORDER_OUTPUT.to_string(),
+        })
+        .to_string()),
+        Endpoint::CreateOrder => create_order(headers, body.unwrap_or_default()),
+        Endpoint::Order => {
+            let id = String::from(js_sys::decode_uri_component(id?).ok()?);
+            order(&id)
+        }
+    })
+}
+
+fn error_body(code: &str) -> String {
+    let field = match code {
+        "AMOUNT_TOO_LOW" | "AMOUNT_TOO_HIGH" => Some("fromAmount"),
+        "INVALID_BENEFICIARY" => Some("beneficiary"),
+        "UNSUPPORTED_CHAIN" => Some("toChain"),
+        "RATE_CHANGED" => Some("rate"),
+        _ => None,
+    };
+    json!({ "error": { "code": code, "message": format!("Mocked {code}"), "field": field } })
+        .to_string()
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateOrderBody {
+    from_amount: u64,
+    beneficiary: String,
+}
+
+fn create_order(headers: &[(&'static str, String)], body: &str) -> Result<String, ApiError> {
+    let Ok(order) = serde_json::from_str::<CreateOrderBody>(body) else {
+        return Err(ApiError::http(400, error_body("BAD_REQUEST")));
+    };
+    if order.from_amount < MIN_ORDER_OUTPUT {
+        return Err(ApiError::http(400, error_body("AMOUNT_TOO_LOW")));
+    }
+    if order.from_amount > AVAILABLE_LIQUIDITY {
+        return Err(ApiError::http(400, error_body("INSUFFICIENT_LIQUIDITY")));
+    }
+
+    let key = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("Idempotency-Key"))
+        .map(|(_, value)| value.clone());
+    if let Some(id) = key
+        .as_ref()
+        .and_then(|key| STATE.with_borrow(|state| state.orders_by_key.get(key).cloned()))
+    {
+        return Err(ApiError::http(409, json!({ "id": id }).to_string()));
+    }
+
+    // The id carries everything needed to answer `/order/{id}`, so orders
+    // survive reloads even though the mock's memory doesn't.
+    let lifecycle = STATE.with_borrow(|state| state.script.lifecycle);
+    let id = format!(
+        "mock-{}-{}-{}-{}",
+        lifecycle.as_str(),
+        js_sys::Date::now() as u64,
+        order.from_amount,
+        order.beneficiary,
+    );
+    if let Some(key) = key {
+        STATE.with_borrow_mut(|state| state.orders_by_key.insert(key, id.clone()));
+    }
+    Ok(json!({ "id": id }).to_string())
+}
+
+fn order(id: &str) -> Result<String, ApiError> {
+    let not_found = || ApiError::http(404, error_body("NOT_FOUND"));
+    let mut parts = id
+        .strip_prefix("mock-")
+        .ok_or_else(not_found)?
+        .splitn(4, '-');
+    let mut next = || parts.next().ok_or_else(not_found);
+    let lifecycle = Lifecycle::parse(next()?).ok_or_else(not_found)?;
+    let created_ms: f64 = next()?.parse().map_err(|_| not_found())?;
+    let from_amount = next()?.parse().map_err(|_| not_found())?;
+    let beneficiary = next()?.to_string();
+
+    let status = lifecycle.status_at(js_sys::Date::now() - created_ms);
+    let order = Order {
+        id: id.to_string(),
+        status,
+        from_amount,
+        deposit_address: DEPOSIT_ADDRESS.to_string(),
+        beneficiary,
+        tx_hash: (status == OrderStatus::Completed).then(|| format!("0x{}", "ab".repeat(32))),
+    };
+    Ok(serde_json::to_string(&order).expect("order serializes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_scripts() {
+        assert_eq!(
+            MockScript::parse("info:500, create-order:RATE_CHANGED,order:timeout,lifecycle:expire"),
+            Ok(MockScript {
+                failures: vec![
+                    (Endpoint::Info, Failure::Status(500)),
+                    (
+                        Endpoint::CreateOrder,
+                        Failure::Code("RATE_CHANGED".to_string())
+                    ),
+                    (Endpoint::Order, Failure::Timeout),
+                ],
+                lifecycle: Lifecycle::Expire,
+            })
+        );
+        assert_eq!(MockScript::parse(""), Ok(MockScript::default()));
+        assert!(MockScript::parse("info").is_err());
+        assert!(MockScript::parse("orders:500").is_err());
+        assert!(MockScript::parse("lifecycle:maybe").is_err());
+    }
+
+    #[test]
+    fn routes_api_paths_only() {
+        let base = "https://untron.finance/api/v2-public";
+        assert_eq!(route(&format!("{base}/info")), Some((Endpoint::Info, None)));
+        assert_eq!(
+            route(&format!("{base}/create-order")),
+            Some((Endpoint::CreateOrder, None))
+        );
+        assert_eq!(
+            route(&format!("{base}/order/mock-1?x=y")),
+            Some((Endpoint::Order, Some("mock-1")))
+        );
+        assert_eq!(route("/config.json"), None);
+        assert_eq!(route("/public/content/en.json"), None);
+    }
+
+    #[test]
+    fn orders_move_through_their_lifecycle() {
+        let at = |lifecycle: Lifecycle, steps: f64| lifecycle.status_at(steps * STEP_MS);
+        assert_eq!(at(Lifecycle::Complete, 0.5), OrderStatus::AwaitingDeposit);
+        assert_eq!(at(Lifecycle::Complete, 1.5), OrderStatus::Processing);
+        assert_eq!(at(Lifecycle::Complete, 2.5), OrderStatus::Completed);
+        assert_eq!(at(Lifecycle::Expire, 1.5), OrderStatus::Expired);
+        assert_eq!(at(Lifecycle::Fail, 1.5), OrderStatus::Processing);
+        assert_eq!(at(Lifecycle::Fail, 2.5), OrderStatus::Failed);
+    }
+}
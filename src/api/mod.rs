@@ -0,0 +1,10 @@
+pub mod error;
+pub mod idempotency;
+pub mod info;
+#[cfg(feature = "mock-api")]
+pub mod mock;
+pub mod order;
+pub mod request;
+
+pub use error::{ApiError, ErrorCode, ErrorField};
+pub use request::Cancellation;
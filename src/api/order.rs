@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::error::ApiError;
+use super::idempotency;
+use super::request::{ApiRequest, Cancellation};
+
+/// Creating an order may take a while on the backend side.
+const CREATE_ORDER_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Safe thanks to the idempotency key: a retry can't create a second order.
+const CREATE_ORDER_RETRIES: u32 = 2;
+
+/// Status the backend answers with when the idempotency key was already used.
+const STATUS_CONFLICT: u16 = 409;
+
+/// Body of `POST /create-order`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOrderRequest {
+    pub to_coin: &'static str,
+    pub to_chain: u32,
+    pub from_amount: u64,
+    pub rate: u64,
+    pub beneficiary: String,
+}
+
+#[derive(Deserialize)]
+pub struct CreateOrderResponse {
+    #[serde(alias = "orderId")]
+    pub id: String,
+}
+
+/// Creates an order through the backend at `api_base_url`.
+///
+/// Every attempt carries an `Idempotency-Key` that stays the same for repeated
+/// submissions of the same body in the same `slot` (see
+/// [`idempotency::key_for`]), so retries, double clicks and resubmits after
+/// a reload all resolve to a single order. When the backend reports the key
+/// as already used, the existing order is returned instead of an error.
+pub async fn create_order(
+    api_base_url: &str,
+    order: &CreateOrderRequest,
+    slot: &str,
+    cancellation: &Cancellation,
+) -> Result<CreateOrderResponse, ApiError> {
+    let body = serde_json::to_string(order).expect("order serializes");
+    let key = idempotency::key_for(slot, &body);
+
+    let result = ApiRequest::post_json(format!("{api_base_url}/create-order"), body)
+        .header("Idempotency-Key", key)
+        .timeout(CREATE_ORDER_TIMEOUT)
+        .retries(CREATE_ORDER_RETRIES)
+        .cancel_with(cancellation)
+        .send_json::<CreateOrderResponse>()
+        .await;
+
+    let result = match result {
+        Err(ApiError::Http { status, body, .. }) if status == STATUS_CONFLICT => {
+            serde_json::from_str(&body).map_err(|_| ApiError::http(status, body))
+        }
+        result => result,
+    };
+
+    // Keep the key only while the outcome is unknown, so resubmitting after a
+    // timeout or a reload can't create a duplicate.
+    let outcome_unknown = matches!(&result, Err(err) if err.is_retryable())
+        || matches!(result, Err(ApiError::Cancelled));
+    if !outcome_unknown {
+        idempotency::clear(slot);
+    }
+    result
+}
+
+/// Lifecycle of an order, as reported by `GET /order/{id}`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+    /// Waiting for the USDT to arrive on Tron.
+    AwaitingDeposit,
+    /// Deposit seen; the transfer on the destination chain is under way.
+    Processing,
+    /// USDT delivered to the beneficiary.
+    Completed,
+    /// No deposit arrived in time.
+    Expired,
+    /// Something went wrong; the deposit will be refunded.
+    Failed,
+    /// A status this build doesn't know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+impl OrderStatus {
+    /// Whether the status can't change anymore, so polling can stop.
+    pub fn is_final(self) -> bool {
+        matches!(self, Self::Completed | Self::Expired | Self::Failed)
+    }
+}
+
+/// Body of `GET /order/{id}`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub id: String,
+    pub status: OrderStatus,
+    /// Amount to deposit, in units.
+    pub from_amount: u64,
+    /// Tron address the deposit has to be sent to.
+    pub deposit_address: String,
+    pub beneficiary: String,
+    /// Destination chain transaction, once the order is completed.
+    #[serde(default)]
+    pub tx_hash: Option<String>,
+}
+
+/// Fetches the current state of order `id`.
+pub async fn fetch_order(
+    api_base_url: String,
+    id: String,
+    cancellation: Cancellation,
+) -> Result<Order, ApiError> {
+    let id = String::from(js_sys::encode_uri_component(&id));
+    ApiRequest::get(format!("{api_base_url}/order/{id}"))
+        .cancel_with(&cancellation)
+        .send_json()
+        .await
+}
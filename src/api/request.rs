@@ -0,0 +1,288 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use gloo_net::http::{Request, RequestBuilder};
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use serde::de::DeserializeOwned;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{AbortController, AbortSignal};
+
+use super::error::ApiError;
+
+// ---- Defaults -------------------------------------------------------------
+
+/// Timeout applied to every attempt unless overridden.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Retries for idempotent GET requests (on top of the first attempt).
+pub const DEFAULT_GET_RETRIES: u32 = 2;
+
+/// Base delay of the exponential backoff between retries.
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Longest backoff between retries, before jitter.
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(8);
+
+// ---- Cancellation ---------------------------------------------------------
+
+/// Aborts every request it is attached to once the reactive owner that created
+/// it is cleaned up (i.e. when the component unmounts).
+#[derive(Clone)]
+pub struct Cancellation {
+    /// `None` while rendering on the server, where nothing is ever sent.
+    controller: Option<AbortController>,
+}
+
+impl Cancellation {
+    /// Creates a token tied to the current reactive owner.
+    pub fn on_cleanup() -> Self {
+        if is_server() {
+            return Self { controller: None };
+        }
+        let controller = AbortController::new().expect("AbortController is supported");
+        let on_unmount = SendWrapper::new(controller.clone());
+        on_cleanup(move || on_unmount.abort());
+        Self {
+            controller: Some(controller),
+        }
+    }
+
+    fn signal(&self) -> Option<AbortSignal> {
+        self.controller.as_ref().map(AbortController::signal)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.signal().is_some_and(|signal| signal.aborted())
+    }
+}
+
+// ---- Requests -------------------------------------------------------------
+
+/// A request to the Untron API (or a static file served next to the app).
+/// Build it with [`ApiRequest::get`] or [`ApiRequest::post_json`] and finish
+/// with [`ApiRequest::send_json`].
+pub struct ApiRequest {
+    url: String,
+    body: Option<String>,
+    headers: Vec<(&'static str, String)>,
+    timeout: Duration,
+    retries: u32,
+    cancellation: Option<Cancellation>,
+}
+
+impl ApiRequest {
+    /// GET request to `url`. Retried by default.
+    pub fn get(url: impl Into<String>) -> Self {
+        Self::new(url.into(), None, DEFAULT_GET_RETRIES)
+    }
+
+    /// POST request to `url` with a serialized JSON body. Never retried
+    /// unless asked to.
+    pub fn post_json(url: impl Into<String>, body: String) -> Self {
+        Self::new(url.into(), Some(body), 0).header("Content-Type", "application/json")
+    }
+
+    fn new(url: String, body: Option<String>, retries: u32) -> Self {
+        Self {
+            url,
+            body,
+            headers: Vec::new(),
+            timeout: DEFAULT_TIMEOUT,
+            retries,
+            cancellation: None,
+        }
+    }
+
+    pub fn header(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.headers.push((name, value.into()));
+        self
+    }
+
+    /// Per-attempt timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Number of retries after the first attempt for retryable failures.
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    pub fn cancel_with(mut self, cancellation: &Cancellation) -> Self {
+        self.cancellation = Some(cancellation.clone());
+        self
+    }
+
+    /// Sends the request (retrying if configured) and decodes a JSON response.
+    pub async fn send_json<T: DeserializeOwned>(self) -> Result<T, ApiError> {
+        let body = self.send_text().await?;
+        serde_json::from_str(&body).map_err(|err| ApiError::Decode(err.to_string()))
+    }
+
+    /// Sends the request (retrying if configured) and returns the raw body.
+    pub async fn send_text(self) -> Result<String, ApiError> {
+        let mut attempt = 0;
+        loop {
+            if self.is_cancelled() {
+                return Err(ApiError::Cancelled);
+            }
+            match self.send_once().await {
+                Err(err) if err.is_retryable() && attempt < self.retries => {
+                    sleep(backoff_delay(attempt, js_sys::Math::random())).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .as_ref()
+            .is_some_and(Cancellation::is_cancelled)
+    }
+
+    async fn send_once(&self) -> Result<String, ApiError> {
+        // Each attempt gets its own controller so a timeout only aborts itself.
+        let controller = AbortController::new().expect("AbortController is supported");
+        let timed_out = Rc::new(Cell::new(false));
+        let timeout_handle = {
+            let controller = controller.clone();
+            let timed_out = timed_out.clone();
+            set_timeout_with_handle(
+                move || {
+                    timed_out.set(true);
+                    controller.abort();
+                },
+                self.timeout,
+            )
+            .ok()
+        };
+        // The cancellation signal outlives the attempt, so its listener is
+        // removed again afterwards.
+        let on_cancel = self
+            .cancellation
+            .as_ref()
+            .and_then(Cancellation::signal)
+            .map(|signal| {
+                let controller = controller.clone();
+                let on_abort = Closure::<dyn Fn()>::new(move || controller.abort());
+                let _ = signal
+                    .add_event_listener_with_callback("abort", on_abort.as_ref().unchecked_ref());
+                (signal, on_abort)
+            });
+
+        let result = self.fetch(controller.signal()).await;
+        if let Some(handle) = timeout_handle {
+            handle.clear();
+        }
+        if let Some((signal, on_abort)) = on_cancel {
+            let _ = signal
+                .remove_event_listener_with_callback("abort", on_abort.as_ref().unchecked_ref());
+        }
+
+        result.map_err(|err| match err {
+            _ if timed_out.get() => ApiError::Timeout,
+            _ if self.is_cancelled() => ApiError::Cancelled,
+            err => err,
+        })
+    }
+
+    async fn fetch(&self, signal: AbortSignal) -> Result<String, ApiError> {
+        #[cfg(feature = "mock-api")]
+        if let Some(response) =
+            super::mock::respond(&self.url, &self.headers, self.body.as_deref()).await
+        {
+            return response;
+        }
+
+        let mut builder = match self.body {
+            Some(_) => Request::post(&self.url),
+            None => Request::get(&self.url),
+        };
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+        let builder: RequestBuilder = builder.abort_signal(Some(&signal));
+        let request = match &self.body {
+            Some(body) => builder.body(body.as_str()),
+            None => builder.build(),
+        }
+        .map_err(|err| ApiError::Network(err.to_string()))?;
+
+        let resp = request
+            .send()
+            .await
+            .map_err(|err| ApiError::Network(err.to_string()))?;
+        let body = resp
+            .text()
+            .await
+            .map_err(|err| ApiError::Network(err.to_string()))?;
+        if !resp.ok() {
+            return Err(ApiError::http(resp.status(), body));
+        }
+        Ok(body)
+    }
+}
+
+// ---- Helpers --------------------------------------------------------------
+
+/// Exponential backoff with jitter: somewhere between half and the full
+/// `RETRY_BASE_DELAY * 2^attempt`, capped at `RETRY_MAX_DELAY`. `random` is
+/// expected in `[0, 1)`.
+fn backoff_delay(attempt: u32, random: f64) -> Duration {
+    let full = RETRY_BASE_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(RETRY_MAX_DELAY);
+    full.mul_f64(0.5 + random.clamp(0.0, 1.0) / 2.0)
+}
+
+/// Resolves after `duration` using `setTimeout`.
+pub(super) async fn sleep(duration: Duration) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        if let Some(window) = web_sys::window() {
+            let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                &resolve,
+                duration.as_millis() as i32,
+            );
+        }
+    });
+    let _ = JsFuture::from(promise).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_per_attempt() {
+        let ms = |attempt| backoff_delay(attempt, 1.0).as_millis();
+        assert_eq!([ms(0), ms(1), ms(2), ms(3)], [500, 1000, 2000, 4000]);
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        assert_eq!(backoff_delay(4, 1.0), RETRY_MAX_DELAY);
+        assert_eq!(backoff_delay(31, 1.0), RETRY_MAX_DELAY);
+        assert_eq!(backoff_delay(u32::MAX, 1.0), RETRY_MAX_DELAY);
+    }
+
+    #[test]
+    fn jitter_stays_within_half_and_full_delay() {
+        assert_eq!(backoff_delay(2, 0.0), Duration::from_millis(1000));
+        assert_eq!(backoff_delay(2, 0.5), Duration::from_millis(1500));
+        // Out-of-range inputs are clamped.
+        assert_eq!(backoff_delay(2, -3.0), Duration::from_millis(1000));
+        assert_eq!(backoff_delay(2, 7.0), Duration::from_millis(2000));
+        for random in [0.0, 0.1, 0.42, 0.99] {
+            let delay = backoff_delay(1, random);
+            assert!(delay >= Duration::from_millis(500) && delay < Duration::from_millis(1000));
+        }
+    }
+}
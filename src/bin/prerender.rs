@@ -0,0 +1,23 @@
+//! Pre-renders the static routes into the site root; run after
+//! `cargo leptos build`, which produces the `hydrate` bundle they load.
+
+#![recursion_limit = "256"]
+
+use leptos::prelude::*;
+use untron_frontend::server::prerender::prerender;
+
+#[tokio::main]
+async fn main() {
+    let conf = get_configuration(Some("Cargo.toml")).expect("valid [package.metadata.leptos]");
+    match prerender(conf.leptos_options).await {
+        Ok(pages) => {
+            for page in pages {
+                println!("wrote {}", page.display());
+            }
+        }
+        Err(err) => {
+            eprintln!("pre-rendering failed: {err}");
+            std::process::exit(1);
+        }
+    }
+}
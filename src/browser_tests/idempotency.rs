@@ -0,0 +1,66 @@
+use wasm_bindgen_test::wasm_bindgen_test;
+use web_sys::Storage;
+
+use crate::api::idempotency::{clear, key_for, STORAGE_KEY};
+
+fn session_storage() -> Storage {
+    web_sys::window()
+        .unwrap()
+        .session_storage()
+        .unwrap()
+        .unwrap()
+}
+
+/// Starts every test from an empty `sessionStorage`.
+fn reset() -> Storage {
+    let storage = session_storage();
+    storage.clear().unwrap();
+    storage
+}
+
+#[wasm_bindgen_test]
+fn a_slot_keeps_its_key_for_the_same_body() {
+    reset();
+    let key = key_for("swap", "body");
+    assert_eq!(key_for("swap", "body"), key);
+    assert_ne!(key_for("swap", "other body"), key);
+}
+
+#[wasm_bindgen_test]
+fn slots_have_independent_keys() {
+    reset();
+    let first = key_for("batch:1:2", "body");
+    let second = key_for("batch:1:3", "body");
+    assert_ne!(first, second);
+    // Writing one slot leaves the other's key in place.
+    assert_eq!(key_for("batch:1:2", "body"), first);
+}
+
+#[wasm_bindgen_test]
+fn clear_only_forgets_its_own_slot() {
+    let storage = reset();
+    let kept = key_for("swap", "body");
+    let cleared = key_for("batch:1:2", "body");
+    clear("batch:1:2");
+    assert_eq!(key_for("swap", "body"), kept);
+    assert_ne!(key_for("batch:1:2", "body"), cleared);
+
+    clear("swap");
+    clear("batch:1:2");
+    assert_eq!(storage.get_item(STORAGE_KEY).unwrap(), None);
+}
+
+#[wasm_bindgen_test]
+fn missing_or_corrupt_storage_is_recovered_from() {
+    let storage = reset();
+    assert!(!key_for("swap", "body").is_empty());
+
+    storage.set_item(STORAGE_KEY, "{not json").unwrap();
+    let key = key_for("swap", "body");
+    assert!(!key.is_empty());
+    assert_eq!(key_for("swap", "body"), key);
+
+    storage.set_item(STORAGE_KEY, r#"{"swap": 42}"#).unwrap();
+    clear("swap");
+    assert!(!key_for("swap", "body").is_empty());
+}
@@ -0,0 +1,136 @@
+//! Headless-browser tests mounting real pages against the `mock-api`
+//! backend. Run them with
+//! `cargo test --target wasm32-unknown-unknown --features mock-api`
+//! (needs `wasm-bindgen-test-runner` and a WebDriver, see the README).
+
+mod idempotency;
+mod swap_flow;
+
+use std::any::Any;
+use std::time::Duration;
+
+use leptos::mount::mount_to;
+use leptos::prelude::*;
+use leptos_router::components::{Route, Router, Routes};
+use leptos_router::path;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::wasm_bindgen_test_configure;
+use web_sys::{Event, EventInit, HtmlButtonElement, HtmlElement, HtmlInputElement};
+
+use crate::api::mock::{self, MockScript};
+use crate::config::app::AppConfig;
+use crate::i18n::Locale;
+use crate::pages::home::Home;
+use crate::wallet::TronWallet;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// How long [`wait_for`] waits before failing.
+const WAIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A page mounted into its own `<div>`, unmounted on drop.
+pub struct Page {
+    root: HtmlElement,
+    _mounted: Box<dyn Any>,
+}
+
+impl Drop for Page {
+    fn drop(&mut self) {
+        self.root.remove();
+    }
+}
+
+impl Page {
+    /// Mounts `Home` with `config`, against a mock answering with `script`.
+    pub fn home(config: AppConfig, script: MockScript) -> Self {
+        mock::reset(script);
+        // Don't let one test's idempotency key leak into the next.
+        if let Ok(Some(storage)) = window().session_storage() {
+            let _ = storage.clear();
+        }
+
+        let root: HtmlElement = document().create_element("div").unwrap().unchecked_into();
+        document().body().unwrap().append_child(&root).unwrap();
+        let mounted = mount_to(root.clone(), move || {
+            provide_context(Locale::En);
+            provide_context(config);
+            provide_context(TronWallet::default());
+            view! {
+                <Router>
+                    <Routes fallback=|| ()>
+                        <Route path=path!("/*any") view=Home />
+                    </Routes>
+                </Router>
+            }
+        });
+        Self {
+            root,
+            _mounted: Box::new(mounted),
+        }
+    }
+
+    pub fn text(&self) -> String {
+        self.root.text_content().unwrap_or_default()
+    }
+
+    pub fn input(&self, selector: &str) -> HtmlInputElement {
+        self.root
+            .query_selector(selector)
+            .unwrap()
+            .unwrap_or_else(|| panic!("no element matches {selector:?}"))
+            .unchecked_into()
+    }
+
+    /// Replaces the value of the input matching `selector`, as typing would.
+    pub fn type_into(&self, selector: &str, value: &str) {
+        let input = self.input(selector);
+        input.set_value(value);
+        let init = EventInit::new();
+        init.set_bubbles(true);
+        input
+            .dispatch_event(&Event::new_with_event_init_dict("input", &init).unwrap())
+            .unwrap();
+    }
+
+    /// The button reading `text`.
+    pub fn button(&self, text: &str) -> HtmlButtonElement {
+        let buttons = self.root.query_selector_all("button").unwrap();
+        (0..buttons.length())
+            .filter_map(|i| buttons.item(i))
+            .map(JsCast::unchecked_into::<HtmlButtonElement>)
+            .find(|button| button.text_content().unwrap_or_default().trim() == text)
+            .unwrap_or_else(|| panic!("no {text:?} button"))
+    }
+
+    /// Waits for the first `/info` response to be rendered.
+    pub async fn wait_for_liquidity(&self) {
+        wait_for("liquidity to load", || {
+            !self.text().contains("Checking available liquidity")
+        })
+        .await;
+    }
+}
+
+pub async fn sleep(duration: Duration) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        window()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(
+                &resolve,
+                duration.as_millis() as i32,
+            )
+            .unwrap();
+    });
+    JsFuture::from(promise).await.unwrap();
+}
+
+/// Polls `condition` until it holds, failing the test after `WAIT_TIMEOUT`.
+pub async fn wait_for(what: &str, mut condition: impl FnMut() -> bool) {
+    let step = Duration::from_millis(20);
+    let mut waited = Duration::ZERO;
+    while !condition() {
+        assert!(waited < WAIT_TIMEOUT, "timed out waiting for {what}");
+        sleep(step).await;
+        waited += step;
+    }
+}
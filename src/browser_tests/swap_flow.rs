@@ -0,0 +1,203 @@
+use serde_json::{json, Value};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use super::{wait_for, Page};
+use crate::api::mock::{self, Endpoint, Failure, MockScript};
+use crate::config::app::AppConfig;
+use crate::utils::address::short_address;
+use crate::utils::units::{string_to_units, SWAP_RATE_UNITS};
+
+const SEND: &str = "#currency-input-send";
+const RECEIVE: &str = "#currency-input-receive";
+const ADDRESS: &str = "input[placeholder='ENS or Address']";
+const BENEFICIARY: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+fn home() -> Page {
+    Page::home(AppConfig::default(), MockScript::default())
+}
+
+/// Fills in a valid amount and beneficiary.
+fn fill_form(page: &Page, amount: &str) {
+    page.type_into(SEND, amount);
+    page.type_into(ADDRESS, BENEFICIARY);
+}
+
+// ---- Conversion -----------------------------------------------------------
+
+#[wasm_bindgen_test]
+async fn send_amount_is_converted_to_receive() {
+    let page = home();
+    page.type_into(SEND, "100");
+    assert_eq!(page.input(RECEIVE).value(), "99.97");
+    assert_eq!(page.input(SEND).value(), "100");
+}
+
+#[wasm_bindgen_test]
+async fn receive_amount_is_converted_to_send() {
+    let page = home();
+    page.type_into(RECEIVE, "100");
+    assert_eq!(page.input(SEND).value(), "100.030009");
+    assert_eq!(page.input(RECEIVE).value(), "100");
+}
+
+#[wasm_bindgen_test]
+async fn invalid_amounts_are_ignored() {
+    let page = home();
+    page.type_into(SEND, "1.5");
+    page.type_into(SEND, "1.5.");
+    assert_eq!(page.input(SEND).value(), "1.5");
+}
+
+// ---- Clamping -------------------------------------------------------------
+
+#[wasm_bindgen_test]
+async fn receive_amount_is_clamped_to_liquidity() {
+    let page = home();
+    page.wait_for_liquidity().await;
+    page.type_into(RECEIVE, "300000");
+    assert_eq!(page.input(RECEIVE).value(), "250000");
+}
+
+#[wasm_bindgen_test]
+async fn send_amount_is_clamped_so_receive_fits_liquidity() {
+    let page = home();
+    page.wait_for_liquidity().await;
+    page.type_into(SEND, "300000");
+    let receive = string_to_units(&page.input(RECEIVE).value()).unwrap();
+    assert!(
+        receive <= 250_000_000_000,
+        "receive {receive} exceeds liquidity"
+    );
+    assert!(
+        receive > 249_999_000_000,
+        "receive {receive} clamped too far"
+    );
+}
+
+// ---- Address --------------------------------------------------------------
+
+#[wasm_bindgen_test]
+async fn complete_address_stays_text() {
+    let page = home();
+    page.type_into(ADDRESS, BENEFICIARY);
+    assert_eq!(page.input(ADDRESS).value(), BENEFICIARY);
+    assert!(!page.text().contains(&short_address(BENEFICIARY)));
+}
+
+#[wasm_bindgen_test]
+async fn malformed_address_stays_text() {
+    let page = home();
+    let malformed = format!("{}g", &BENEFICIARY[..41]);
+    page.type_into(ADDRESS, &malformed);
+    assert_eq!(page.input(ADDRESS).value(), malformed);
+    assert!(!page.text().contains(&malformed));
+}
+
+// ---- Swap button ----------------------------------------------------------
+
+#[wasm_bindgen_test]
+async fn swap_needs_amount_address_and_liquidity() {
+    let page = home();
+    assert!(page.button("Untron!").disabled(), "enabled while loading");
+
+    page.wait_for_liquidity().await;
+    assert!(page.button("Untron!").disabled(), "enabled without input");
+
+    page.type_into(SEND, "100");
+    assert!(page.button("Untron!").disabled(), "enabled without address");
+
+    page.type_into(ADDRESS, BENEFICIARY);
+    assert!(
+        !page.button("Untron!").disabled(),
+        "disabled with a valid form"
+    );
+}
+
+#[wasm_bindgen_test]
+async fn swap_is_disabled_below_minimum_order() {
+    let page = home();
+    page.wait_for_liquidity().await;
+    fill_form(&page, "0.5");
+    assert!(page.button("Untron!").disabled());
+}
+
+#[wasm_bindgen_test]
+async fn swap_is_disabled_while_liquidity_is_unavailable() {
+    let script = MockScript {
+        failures: vec![(Endpoint::Info, Failure::Status(500))],
+        ..MockScript::default()
+    };
+    let page = Page::home(AppConfig::default(), script);
+    wait_for("the liquidity error", || {
+        page.text().contains("Couldn't load available liquidity")
+    })
+    .await;
+    fill_form(&page, "100");
+    assert!(page.button("Untron!").disabled());
+}
+
+#[wasm_bindgen_test]
+async fn swap_is_disabled_when_orders_are_switched_off() {
+    let mut config = AppConfig::default();
+    config.features.orders = false;
+    let page = Page::home(config, MockScript::default());
+    page.wait_for_liquidity().await;
+    fill_form(&page, "100");
+    assert!(page.button("Untron!").disabled());
+    assert!(page.text().contains("Swaps are paused"));
+}
+
+// ---- Create order ---------------------------------------------------------
+
+#[wasm_bindgen_test]
+async fn create_order_sends_the_form() {
+    let page = home();
+    page.wait_for_liquidity().await;
+    fill_form(&page, "100");
+    mock::take_requests();
+    page.button("Untron!").click();
+
+    let mut body = None;
+    wait_for("the create-order request", || {
+        body = body.take().or_else(|| {
+            mock::take_requests()
+                .into_iter()
+                .find(|(endpoint, _)| *endpoint == Endpoint::CreateOrder)
+                .and_then(|(_, body)| body)
+        });
+        body.is_some()
+    })
+    .await;
+    let body: Value = serde_json::from_str(&body.unwrap()).unwrap();
+    assert_eq!(
+        body,
+        json!({
+            "toCoin": "usdt",
+            "toChain": AppConfig::default().default_chain().id,
+            "fromAmount": 100_000_000,
+            "rate": SWAP_RATE_UNITS,
+            "beneficiary": BENEFICIARY,
+        })
+    );
+}
+
+#[wasm_bindgen_test]
+async fn field_errors_point_at_the_amount() {
+    let script = MockScript {
+        failures: vec![(
+            Endpoint::CreateOrder,
+            Failure::Code("AMOUNT_TOO_LOW".to_string()),
+        )],
+        ..MockScript::default()
+    };
+    let page = Page::home(AppConfig::default(), script);
+    page.wait_for_liquidity().await;
+    fill_form(&page, "100");
+    page.button("Untron!").click();
+
+    wait_for("the amount input to be marked invalid", || {
+        page.input(SEND).closest(".ring-red-500").unwrap().is_some()
+    })
+    .await;
+    assert!(!page.text().contains("Processing…"));
+}
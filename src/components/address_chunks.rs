@@ -0,0 +1,61 @@
+use leptos::prelude::*;
+
+use crate::utils::address::address_chunks;
+
+/// Colours the groups cycle through, so neighbouring groups stand apart.
+const CHUNK_COLORS: [&str; 4] = [
+    "text-[#1c1c1c]",
+    "text-[#2563eb]",
+    "text-[#16a34a]",
+    "text-[#9333ea]",
+];
+
+// ---- Component ------------------------------------------------------------
+
+/// A full address in colour-coded groups of four hex digits, which are far
+/// easier to compare than one long string.
+#[component]
+pub fn AddressChunks(
+    /// Address to show
+    #[prop(into)]
+    address: String,
+    /// Address to compare with; groups that differ from it are highlighted
+    #[prop(optional, into)]
+    compare_to: Option<String>,
+) -> impl IntoView {
+    let others = compare_to
+        .as_deref()
+        .map(|other| {
+            address_chunks(other)
+                .into_iter()
+                .map(str::to_lowercase)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let chunks = address_chunks(&address)
+        .into_iter()
+        .enumerate()
+        .map(|(i, chunk)| {
+            let differs = others
+                .get(i)
+                .is_some_and(|other| *other != chunk.to_lowercase());
+            let class = if differs {
+                "px-0.5 rounded bg-red-100 text-red-600 underline"
+            } else {
+                CHUNK_COLORS[i % CHUNK_COLORS.len()]
+            };
+            view! { <span class=class>{chunk.to_string()}</span> }
+        })
+        .collect_view();
+
+    // Screen readers get the address in one piece.
+    view! {
+        <span class="font-mono">
+            <span class="sr-only">{address}</span>
+            <span class="inline-flex flex-wrap gap-x-1.5" aria-hidden="true">
+                <span class="text-muted-foreground">"0x"</span>
+                {chunks}
+            </span>
+        </span>
+    }
+}
@@ -1,9 +1,9 @@
 use leptos::ev::Event;
 use leptos::prelude::*;
-use std::rc::Rc;
 use wasm_bindgen::JsCast;
 use web_sys::HtmlInputElement;
 
+use crate::utils::quote::{Quote, QuoteSide};
 use crate::utils::units::*;
 
 // ---- Component ------------------------------------------------------------
@@ -18,158 +18,108 @@ pub fn CurrencyInput(
     /// Path to an icon asset
     currency_icon: &'static str,
     /// Optional human-readable currency name (e.g. "USDT Tron")
-    currency_name: &'static str,
-    /// Controlled value coming from the parent
     #[prop(into)]
-    value: RwSignal<String>,
-    /// Optional callback invoked when the value changes (send side for receive inputs and vice-versa)
-    #[prop(optional)]
-    on_change: Option<Rc<dyn Fn(String)>>,
+    currency_name: Signal<String>,
+    /// Which side of the quote this input shows and edits
+    side: QuoteSide,
+    /// Quote shared with the other input. The input renders its side of it and
+    /// replaces it on every edit; nothing else is kept locally.
+    quote: RwSignal<Quote>,
     /// Maximum liquidity in units (scaled by `SCALING_FACTOR`). Accepts a signal
-    /// so the component reacts when the value changes.
+    /// so the component reacts when the value changes. `None` while liquidity is
+    /// still unknown, in which case no clamping happens.
     #[prop(into)]
-    max_units: Signal<u64>,
-    /// Whether this input represents the receive side
-    #[prop(optional, default = false)]
-    is_receive: bool,
+    max_units: Signal<Option<u64>>,
     /// Current swap rate (scaled by `SCALING_FACTOR`)
-    #[prop(optional)]
-    swap_rate_units: Option<u64>,
+    swap_rate_units: u64,
+    /// Minimum order output in units (scaled by `SCALING_FACTOR`); unset while unknown
+    #[prop(optional, into)]
+    min_units: MaybeProp<u64>,
     /// Show the "max output" warning banner
     #[prop(optional, default = false)]
     show_max_output: bool,
+    /// Show the "min output" warning banner
+    #[prop(optional, default = false)]
+    show_min_output: bool,
+    /// Error for this field reported by the backend; highlights the input
+    #[prop(optional, into)]
+    error: MaybeProp<String>,
+    /// Amount (in this side's units) filled in by the "Max" button; the button
+    /// is hidden while unset
+    #[prop(optional, into)]
+    max_fill_units: MaybeProp<u64>,
+    /// Shows the amount without letting it be edited (e.g. a fixed payment)
+    #[prop(optional)]
+    read_only: bool,
 ) -> impl IntoView {
-    // Internal signals mirror the React `useState` hooks.
-    let input_value = RwSignal::new(value.get_untracked());
-    let show_max_warning = RwSignal::new(false);
+    let input_id = match side {
+        QuoteSide::Send => "currency-input-send",
+        QuoteSide::Receive => "currency-input-receive",
+    };
 
-    // Sync internal value when the external value changes (mimics React useEffect).
-    Effect::new(move |_| {
-        input_value.set(value.get());
-    });
+    // Warnings are derived from the quote, so they follow edits of either input.
+    let show_max_warning = move || quote.with(|q| q.clamped);
+    let show_min_warning = move || {
+        let output = quote.with(|q| q.units(QuoteSide::Receive, swap_rate_units));
+        match (output, min_units.get()) {
+            // Empty inputs aren't orders yet, so they never trip the minimum.
+            (Some(output), Some(min)) => output > 0 && output < min,
+            _ => false,
+        }
+    };
 
     // Handler for <input> events.
-    let handle_input = {
-        let input_value = input_value.clone();
-        let show_max_warning = show_max_warning.clone();
-        let value_signal = value.clone();
-        let on_change = on_change.clone();
-        move |ev: Event| {
-            // Extract raw string value from the <input /> element.
-            let target = ev.target().expect("event should have target");
-            let input_el: HtmlInputElement = target
-                .dyn_into()
-                .expect("target should be HtmlInputElement");
-            let new_value: String = input_el
-                .value()
-                .chars()
-                .filter(|c| c.is_ascii_digit() || *c == '.')
-                .collect();
-
-            // Prevent multiple decimal points.
-            if new_value.matches('.').count() > 1 {
-                return;
-            }
-
-            // Early exit if string_to_units would fail.
-            let new_units_opt = if !new_value.is_empty() {
-                string_to_units(&new_value)
-            } else {
-                Some(0)
-            };
-            if new_units_opt.is_none() {
-                return;
-            }
-            let new_units = new_units_opt.unwrap();
-
-            // Helper closures for common operations.
-            let exceeds_max = |units: u64, max: u64| units > max;
-
-            // Branches mirror the TS implementation.
-            if is_receive {
-                // RECEIVE input branch --------------------------------------
-                if let (Some(rate), true) = (swap_rate_units, !new_value.is_empty()) {
-                    // Convert receive -> send and propagate.
-                    let send_units = convert_receive_to_send(new_units, rate);
-                    let send_value = units_to_string(send_units);
-
-                    let exceeds = exceeds_max(new_units, max_units.get());
-                    show_max_warning.set(exceeds);
-                    if exceeds {
-                        // Clamp to max and exit.
-                        let max_receive_display = units_to_string(max_units.get());
-                        let max_input_units = convert_receive_to_send(max_units.get(), rate);
-                        let max_input_display = units_to_string(max_input_units);
-                        input_value.set(max_receive_display.clone());
-                        if let Some(cb) = &on_change {
-                            cb(max_input_display);
-                        }
-                        show_max_warning.set(false);
-                        return;
-                    }
-
-                    input_value.set(new_value.clone());
-                    if let Some(cb) = &on_change {
-                        cb(send_value);
-                    }
-                    return;
-                }
+    let handle_input = move |ev: Event| {
+        // Extract raw string value from the <input /> element.
+        let target = ev.target().expect("event should have target");
+        let input_el: HtmlInputElement = target
+            .dyn_into()
+            .expect("target should be HtmlInputElement");
+        let new_value: String = input_el
+            .value()
+            .chars()
+            .filter(|c| c.is_ascii_digit() || *c == '.')
+            .collect();
 
-                // Fallback validation when we cannot compute send value.
-                if !new_value.is_empty() {
-                    let exceeds = exceeds_max(new_units, max_units.get());
-                    show_max_warning.set(exceeds);
-                    if exceeds {
-                        let max_receive_display = units_to_string(max_units.get());
-                        input_value.set(max_receive_display.clone());
-                        if let Some(cb) = &on_change {
-                            cb(max_receive_display);
-                        }
-                        show_max_warning.set(false);
-                        return;
-                    }
-                }
-            } else {
-                // SEND input branch -----------------------------------------
-                if let (Some(rate), true) = (swap_rate_units, !new_value.is_empty()) {
-                    let output_units = convert_send_to_receive(new_units, rate);
-                    let exceeds = exceeds_max(output_units, max_units.get());
-                    show_max_warning.set(exceeds);
-                    if exceeds {
-                        let max_input_units = convert_receive_to_send(max_units.get(), rate);
-                        let max_input_display = units_to_string(max_input_units);
-                        input_value.set(max_input_display.clone());
-                        if let Some(cb) = &on_change {
-                            cb(max_input_display.clone());
-                        }
-                        show_max_warning.set(false);
-                        return;
-                    }
-                }
-            }
+        match Quote::edit(side, &new_value, swap_rate_units, max_units.get_untracked()) {
+            Some(new_quote) => quote.set(new_quote),
+            // Invalid edit (e.g. a second decimal point): restore the previous text.
+            None => input_el.set_value(&quote.with_untracked(|q| q.text(side, swap_rate_units))),
+        }
+    };
 
-            // No special handling required => just propagate the plain value.
-            input_value.set(new_value.clone());
-            value_signal.set(new_value.clone());
-            if let Some(cb) = &on_change {
-                cb(new_value);
-            }
+    let fill_max = move |_| {
+        if let Some(units) = max_fill_units.get_untracked() {
+            quote.set(Quote {
+                side,
+                input: units_to_string(units),
+                clamped: false,
+            });
         }
     };
 
     // Render ----------------------------------------------------------------
     view! {
-        <div class="bg-card rounded-[44px] pl-6 pr-[15px] w-full max-w-[560px] flex items-center h-[135px]">
+        <div class=move || {
+            let ring = if error.get().is_some() { "ring-2 ring-red-500" } else { "" };
+            format!(
+                "bg-card rounded-[44px] pl-6 pr-[15px] w-full max-w-[560px] flex items-center h-[135px] {ring}",
+            )
+        }>
             <div class="flex-1">
-                <label class="text-[18px] font-normal text-foreground mb-0 leading-none block">
+                <label
+                    for=input_id
+                    class="text-[18px] font-normal text-foreground mb-0 leading-none block"
+                >
                     {label}
                 </label>
                 <input
-                    id=format!("currency-input-{currency}")
+                    id=input_id
                     type="text"
                     inputmode="decimal"
-                    prop:value=move || input_value.get()
+                    prop:value=move || quote.with(|q| q.text(side, swap_rate_units))
                     on:input=handle_input
+                    readonly=read_only
                     placeholder="0.0"
                     class="text-[36px] font-semibold outline-none w-full text-foreground p-0 leading-none placeholder:text-muted-foreground"
                 />
@@ -177,19 +127,43 @@ pub fn CurrencyInput(
                     <p class="text-normal text-muted-foreground mt-[0px] leading-none">
                         {currency}
                     </p>
+                    <Show when=move || !read_only && max_fill_units.get().is_some()>
+                        <button
+                            type="button"
+                            class="text-sm font-medium text-foreground bg-background rounded-full px-3 py-0.5"
+                            on:click=fill_max
+                        >
+                            "Max"
+                        </button>
+                    </Show>
                 </div>
                 <Show
-                    when=move || show_max_output && show_max_warning.get() && (max_units.get() > 0)
+                    when=move || {
+                        show_max_output && show_max_warning()
+                            && max_units.get().is_some_and(|max| max > 0)
+                    }
                     fallback=|| view! { <div></div> }
                 >
                     {
                         let msg = format!(
                             "Maximum output is {} USDT",
-                            units_to_string(max_units.get()),
+                            units_to_string(max_units.get().unwrap_or_default()),
                         );
                         view! { <div class="text-xs text-red-500 mt-1">{msg}</div> }
                     }
                 </Show>
+                <Show when=move || show_min_output && show_min_warning()>
+                    {move || {
+                        let msg = format!(
+                            "Minimum output is {} USDT",
+                            units_to_string(min_units.get().unwrap_or_default()),
+                        );
+                        view! { <div class="text-xs text-red-500 mt-1">{msg}</div> }
+                    }}
+                </Show>
+                {move || {
+                    error.get().map(|err| view! { <div class="text-xs text-red-500 mt-1">{err}</div> })
+                }}
             </div>
             <div class="flex items-center justify-center pt-[40px] pb-[32px]">
                 <img
@@ -1,58 +1,233 @@
+use std::borrow::Cow;
+
+use leptos::ev::KeyboardEvent;
+use leptos::html;
 use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::components::rich_text::RichText;
+use crate::utils::markup::plain_text;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct FaqItem {
-    pub question: &'static str,
-    pub answer: &'static str,
-    pub emoji: Option<&'static str>,
+    /// Stable identifier used in URL fragments (`#faq-<slug>`); never change
+    /// it once published, links in the wild depend on it.
+    pub slug: Cow<'static, str>,
+    pub question: Cow<'static, str>,
+    /// Answer in the `utils::markup` format (never raw HTML).
+    pub answer: Cow<'static, str>,
+    #[serde(default)]
+    pub emoji: Option<Cow<'static, str>>,
 }
 
+/// FAQ list following the WAI-ARIA accordion pattern: every question is a
+/// `<button>` controlling its answer panel, Enter/Space toggle it, Up/Down
+/// move between questions and Home/End jump to the first/last one.
+///
+/// Items are deep-linkable: `#<id_prefix>-<slug>` in the URL opens the item
+/// and scrolls to it, on load and whenever the fragment changes.
 #[component]
-pub fn FaqAccordion(items: &'static [FaqItem]) -> impl IntoView {
+pub fn FaqAccordion(
+    #[prop(into)] items: Cow<'static, [FaqItem]>,
+    /// Only keep one answer open at a time
+    #[prop(optional, default = false)]
+    single_open: bool,
+    /// Prefix for element ids and URL fragments, so several accordions can share a page
+    #[prop(optional, default = "faq")]
+    id_prefix: &'static str,
+    /// Show a search box filtering questions and answers
+    #[prop(optional, default = false)]
+    searchable: bool,
+) -> impl IntoView {
     // Track open indexes as a Vec of usize
     let open_indexes = RwSignal::new(Vec::new());
+    let headers: Vec<NodeRef<html::Button>> = items.iter().map(|_| NodeRef::new()).collect();
+    let slugs = StoredValue::new(
+        items
+            .iter()
+            .map(|item| item.slug.to_string())
+            .collect::<Vec<_>>(),
+    );
+    let copied_index = RwSignal::new(None::<usize>);
 
-    let toggle_accordion = move |index: usize| {
+    // ---- Search ------------------------------------------------------------
+    let query = RwSignal::new(String::new());
+    let haystacks: Vec<String> = items
+        .iter()
+        .map(|item| format!("{}\n{}", item.question, plain_text(&item.answer)).to_lowercase())
+        .collect();
+    let visible = Memo::new(move |_| {
+        let query = query.get().trim().to_lowercase();
+        haystacks
+            .iter()
+            .enumerate()
+            .filter(|(_, haystack)| haystack.contains(&query))
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>()
+    });
+
+    let open = move |index: usize| {
         open_indexes.update(|open| {
-            if let Some(pos) = open.iter().position(|&i| i == index) {
-                open.remove(pos);
-            } else {
+            if single_open {
+                *open = vec![index];
+            } else if !open.contains(&index) {
                 open.push(index);
             }
         });
     };
 
+    let toggle_accordion = move |index: usize| {
+        if open_indexes.with_untracked(|open| open.contains(&index)) {
+            open_indexes.update(|open| open.retain(|&i| i != index));
+        } else {
+            open(index);
+        }
+    };
+
+    // ---- Deep links --------------------------------------------------------
+    let item_id = move |slug: &str| format!("{id_prefix}-{slug}");
+    let open_from_hash = move || {
+        let hash = window().location().hash().unwrap_or_default();
+        let Some(index) = slugs.with_value(|slugs| {
+            slugs
+                .iter()
+                .position(|slug| hash.strip_prefix('#') == Some(item_id(slug).as_str()))
+        }) else {
+            return;
+        };
+        // A stale search could be hiding the linked item.
+        query.set(String::new());
+        open(index);
+        let id = slugs.with_value(|slugs| item_id(&slugs[index]));
+        request_animation_frame(move || {
+            if let Some(el) = document().get_element_by_id(&id) {
+                el.scroll_into_view();
+            }
+        });
+    };
+    Effect::new(move |_| open_from_hash());
+    let hash_listener = window_event_listener_untyped("hashchange", move |_| open_from_hash());
+    on_cleanup(move || hash_listener.remove());
+
+    let copy_link = move |index: usize| {
+        let location = window().location();
+        let url = format!(
+            "{}{}#{}",
+            location.origin().unwrap_or_default(),
+            location.pathname().unwrap_or_default(),
+            slugs.with_value(|slugs| item_id(&slugs[index])),
+        );
+        let _ = window().navigator().clipboard().write_text(&url);
+        copied_index.set(Some(index));
+        set_timeout(
+            move || {
+                if copied_index.get_untracked() == Some(index) {
+                    copied_index.set(None);
+                }
+            },
+            std::time::Duration::from_secs(2),
+        );
+    };
+
+    let focus_header = {
+        let headers = headers.clone();
+        move |index: usize| {
+            if let Some(button) = headers.get(index).and_then(|header| header.get()) {
+                let _ = button.focus();
+            }
+        }
+    };
+
+    // Navigation only visits items matching the current search.
+    let on_keydown = move |index: usize, ev: KeyboardEvent| {
+        let visible = visible.get_untracked();
+        let Some(pos) = visible.iter().position(|&i| i == index) else {
+            return;
+        };
+        let last = visible.len() - 1;
+        let target = match ev.key().as_str() {
+            "ArrowDown" => Some(if pos == last { 0 } else { pos + 1 }),
+            "ArrowUp" => Some(if pos == 0 { last } else { pos - 1 }),
+            "Home" => Some(0),
+            "End" => Some(last),
+            // Enter and Space are handled natively by <button>.
+            _ => None,
+        };
+        if let Some(target) = target {
+            ev.prevent_default();
+            focus_header(visible[target]);
+        }
+    };
+
     view! {
         <div class="space-y-4">
+            <Show when=move || searchable>
+                <input
+                    type="search"
+                    aria-label="Search FAQ"
+                    placeholder="Search questions"
+                    class="w-full bg-card rounded-[22px] px-6 py-[14px] text-lg outline-none placeholder:text-muted-foreground"
+                    prop:value=move || query.get()
+                    on:input:target=move |ev| query.set(ev.target().value())
+                />
+            </Show>
+            <Show when=move || visible.with(Vec::is_empty)>
+                <p class="text-center text-muted-foreground">"No matching questions."</p>
+            </Show>
             {items
-                .iter()
+                .into_owned()
+                .into_iter()
                 .enumerate()
                 .map(|(index, item)| {
                     let is_open = move || open_indexes.get().contains(&index);
+                    let id = item_id(&item.slug);
+                    let header_id = format!("{id}-header");
+                    let panel_id = format!("{id}-panel");
+                    let on_keydown = on_keydown.clone();
                     view! {
-                        <div class="bg-card rounded-[22px] overflow-hidden transition-all duration-100">
-                            <div
-                                class="px-6 py-[22px] cursor-pointer select-none"
-                                on:click=move |_| toggle_accordion(index)
-                            >
-                                <div class="w-full flex items-center justify-between text-left">
+                        <div
+                            id=id
+                            class="bg-card rounded-[22px] overflow-hidden scroll-mt-4"
+                            class:hidden=move || !visible.with(|v| v.contains(&index))
+                        >
+                            <h3>
+                                <button
+                                    type="button"
+                                    id=header_id.clone()
+                                    node_ref=headers[index]
+                                    aria-expanded=move || is_open().to_string()
+                                    aria-controls=panel_id.clone()
+                                    class="w-full px-6 py-[22px] flex items-center justify-between text-left select-none"
+                                    on:click=move |_| toggle_accordion(index)
+                                    on:keydown=move |ev| on_keydown(index, ev)
+                                >
                                     <span class="font-medium text-lg flex items-center">
                                         {item.question}
                                         {item
                                             .emoji
-                                            .map(|e| view! { <span class="ml-1">{e}</span> })}
+                                            .map(|e| {
+                                                view! {
+                                                    <span class="ml-1" aria-hidden="true">
+                                                        {e}
+                                                    </span>
+                                                }
+                                            })}
                                     </span>
-                                    <span class=move || {
-                                        let rotate = if is_open() {
-                                            "rotate-180"
-                                        } else {
-                                            "rotate-0"
-                                        };
-                                        format!(
-                                            "transition-transform duration-100 ease-in-out w-[28px] h-[28px] inline-block {}",
-                                            rotate,
-                                        )
-                                    }>
+                                    <span
+                                        aria-hidden="true"
+                                        class=move || {
+                                            let rotate = if is_open() {
+                                                "rotate-180"
+                                            } else {
+                                                "rotate-0"
+                                            };
+                                            format!(
+                                                "transition-transform duration-200 ease-in-out w-[28px] h-[28px] inline-block {}",
+                                                rotate,
+                                            )
+                                        }
+                                    >
                                         <svg
                                             xmlns="http://www.w3.org/2000/svg"
                                             fill="none"
@@ -67,15 +242,44 @@ pub fn FaqAccordion(items: &'static [FaqItem]) -> impl IntoView {
                                             />
                                         </svg>
                                     </span>
-                                </div>
-                                <Show when=is_open>
-                                    <div
-                                        class="mt-[4px] text-base font-normal text-muted-foreground overflow-hidden transition-all duration-100"
-                                        style="max-height: 500px;"
-                                    >
-                                        <div inner_html=item.answer />
+                                </button>
+                            </h3>
+                            // Animating grid rows from 0fr to 1fr transitions to the
+                            // content's natural height, whatever it is.
+                            <div
+                                id=panel_id
+                                role="region"
+                                aria-labelledby=header_id
+                                inert=move || !is_open()
+                                class=move || {
+                                    let rows = if is_open() {
+                                        "grid-rows-[1fr]"
+                                    } else {
+                                        "grid-rows-[0fr]"
+                                    };
+                                    format!(
+                                        "grid transition-[grid-template-rows] duration-200 ease-in-out {rows}",
+                                    )
+                                }
+                            >
+                                <div class="overflow-hidden">
+                                    <div class="px-6 pb-[22px] -mt-[18px] text-base font-normal text-muted-foreground">
+                                        <RichText source=item.answer.into_owned() />
+                                        <button
+                                            type="button"
+                                            class="mt-2 text-sm text-muted-foreground hover:text-foreground"
+                                            on:click=move |_| copy_link(index)
+                                        >
+                                            {move || {
+                                                if copied_index.get() == Some(index) {
+                                                    "Link copied"
+                                                } else {
+                                                    "Copy link"
+                                                }
+                                            }}
+                                        </button>
                                     </div>
-                                </Show>
+                                </div>
                             </div>
                         </div>
                     }
@@ -1,9 +1,11 @@
+use std::borrow::Cow;
+
 use leptos::prelude::*;
 
 use crate::config::footer::FooterSection;
 
 #[component]
-pub fn Footer(footer_links: &'static [FooterSection]) -> impl IntoView {
+pub fn Footer(#[prop(into)] footer_links: Cow<'static, [FooterSection]>) -> impl IntoView {
     view! {
         <footer class="py-12">
             <div class="w-full max-w-[1200px] mx-auto px-4">
@@ -19,7 +21,8 @@ pub fn Footer(footer_links: &'static [FooterSection]) -> impl IntoView {
                     </div>
                     <div class="flex-1 flex flex-wrap">
                         {footer_links
-                            .iter()
+                            .into_owned()
+                            .into_iter()
                             .enumerate()
                             .map(|(index, section)| {
                                 let pl_class = if index > 0 { "pl-4 sm:pl-0" } else { "" };
@@ -29,7 +32,8 @@ pub fn Footer(footer_links: &'static [FooterSection]) -> impl IntoView {
                                         <ul class="space-y-0.5 text-base font-normal text-muted-foreground">
                                             {section
                                                 .links
-                                                .iter()
+                                                .into_owned()
+                                                .into_iter()
                                                 .map(|link| {
                                                     view! {
                                                         <li>
@@ -1,7 +1,13 @@
+pub mod address_chunks;
 pub mod currency_input;
 pub mod faq_accordion;
 pub mod footer;
 pub mod header;
 pub mod order;
+pub mod page_meta;
+pub mod quote_breakdown;
+pub mod recipient_combobox;
+pub mod rich_text;
+pub mod untron_widget;
 
 pub use faq_accordion::{FaqAccordion, FaqItem};
@@ -0,0 +1,59 @@
+use leptos::prelude::*;
+
+use crate::api::order::OrderStatus;
+
+/// Happy-path steps, in order.
+const STEPS: &[(OrderStatus, &str)] = &[
+    (OrderStatus::AwaitingDeposit, "Waiting for your deposit"),
+    (OrderStatus::Processing, "Sending USDT"),
+    (OrderStatus::Completed, "Done"),
+];
+
+/// Step list highlighting where an order currently is.
+#[component]
+pub fn OrderProgress(#[prop(into)] status: Signal<OrderStatus>) -> impl IntoView {
+    let current = move || {
+        STEPS
+            .iter()
+            .position(|(step, _)| *step == status.get())
+            .unwrap_or(0)
+    };
+
+    view! {
+        <ol class="space-y-2">
+            {STEPS
+                .iter()
+                .enumerate()
+                .map(|(index, (_, label))| {
+                    let class = move || {
+                        let color = if index < current() || status.get() == OrderStatus::Completed {
+                            "text-foreground"
+                        } else if index == current() {
+                            "text-foreground font-medium"
+                        } else {
+                            "text-muted-foreground"
+                        };
+                        format!("flex items-center gap-2 text-lg {color}")
+                    };
+                    view! {
+                        <li
+                            class=class
+                            aria-current=move || (index == current()).then_some("step")
+                        >
+                            <span aria-hidden="true">
+                                {move || {
+                                    if index < current() || status.get() == OrderStatus::Completed {
+                                        "✓"
+                                    } else {
+                                        "•"
+                                    }
+                                }}
+                            </span>
+                            {*label}
+                        </li>
+                    }
+                })
+                .collect::<Vec<_>>()}
+        </ol>
+    }
+}
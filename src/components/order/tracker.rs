@@ -0,0 +1,135 @@
+use leptos::prelude::*;
+use std::time::Duration;
+
+// Local imports
+use crate::api::order::{fetch_order, Order, OrderStatus};
+use crate::api::Cancellation;
+use crate::components::order::progress::OrderProgress;
+use crate::config::app::use_app_config;
+use crate::i18n::{api_error_message, use_locale};
+use crate::utils::units::units_to_string;
+
+// ---------------- Constants ----------------
+/// How often a pending order is refetched.
+const ORDER_REFRESH_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Live status of an order: progress, payment instructions and outcome.
+#[component]
+pub fn OrderTracker(
+    #[prop(into)] order_id: Signal<String>,
+    /// Called with every successfully fetched version of the order.
+    #[prop(optional, into)]
+    on_update: Option<Callback<Order>>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let api_base_url = StoredValue::new(use_app_config().api_base_url);
+    let cancellation = Cancellation::on_cleanup();
+
+    let order = LocalResource::new(move || {
+        fetch_order(
+            api_base_url.get_value(),
+            order_id.get(),
+            cancellation.clone(),
+        )
+    });
+    // Poll until the order can't change anymore, or fetching it can't succeed
+    // (e.g. an unknown id). Effects only run in the browser, so the server
+    // never starts a timer.
+    Effect::new(move |_| {
+        if let Ok(handle) = set_interval_with_handle(
+            move || {
+                let done = order.get_untracked().is_some_and(|result| match result {
+                    Ok(order) => order.status.is_final(),
+                    Err(err) => !err.is_retryable(),
+                });
+                if !done {
+                    order.refetch();
+                }
+            },
+            ORDER_REFRESH_INTERVAL,
+        ) {
+            on_cleanup(move || handle.clear());
+        }
+    });
+
+    let details = move |order: Order| {
+        let amount = units_to_string(order.from_amount);
+        match order.status {
+            OrderStatus::AwaitingDeposit => view! {
+                <p class="text-base">
+                    "Send exactly " <strong>{amount} " USDT"</strong> " on Tron to"
+                </p>
+                <p class="font-mono text-base break-all bg-white rounded-[14px] px-4 py-2">
+                    {order.deposit_address}
+                </p>
+            }
+            .into_any(),
+            OrderStatus::Processing => view! {
+                <p class="text-base">
+                    {format!("Deposit received. Sending {amount} USDT to {}…", order.beneficiary)}
+                </p>
+            }
+            .into_any(),
+            OrderStatus::Completed => view! {
+                <p class="text-base">{format!("Sent to {}.", order.beneficiary)}</p>
+                {order
+                    .tx_hash
+                    .map(|hash| {
+                        view! { <p class="font-mono text-sm break-all text-muted-foreground">{hash}</p> }
+                    })}
+            }
+            .into_any(),
+            OrderStatus::Expired => view! {
+                <p class="text-base text-red-500">
+                    "No deposit arrived in time, so this order has expired."
+                </p>
+            }
+            .into_any(),
+            OrderStatus::Failed => view! {
+                <p class="text-base text-red-500">
+                    "This order failed. Your deposit will be refunded."
+                </p>
+            }
+            .into_any(),
+            OrderStatus::Unknown => view! {
+                <p class="text-base text-muted-foreground">"Checking order status…"</p>
+            }
+            .into_any(),
+        }
+    };
+
+    if let Some(on_update) = on_update {
+        Effect::new(move |_| {
+            if let Some(Ok(order)) = order.get() {
+                on_update.run(order);
+            }
+        });
+    }
+
+    view! {
+        // Orders are only fetched in the browser; the server sends the fallback.
+        <Transition fallback=|| {
+            view! { <p class="text-base text-muted-foreground">"Loading order…"</p> }
+        }>
+        {move || order.get().map(|result| match result {
+            Ok(order) => view! {
+                <OrderProgress status=order.status />
+                <div class="space-y-2">{details(order)}</div>
+            }
+            .into_any(),
+            Err(err) => {
+                let retry = if err.is_retryable() { " Retrying shortly…" } else { "" };
+                view! {
+                    <p class="text-base text-red-500">
+                        {format!(
+                            "Couldn't load the order ({}).{retry}",
+                            api_error_message(&err, locale),
+                        )}
+                    </p>
+                }
+                .into_any()
+            }
+        })}
+        </Transition>
+    }
+}
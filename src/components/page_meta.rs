@@ -0,0 +1,108 @@
+use std::borrow::Cow;
+
+use leptos::prelude::*;
+use leptos_meta::{Link, Meta, Script, Title};
+use serde_json::json;
+
+use crate::components::FaqItem;
+use crate::config::app::use_app_config;
+use crate::utils::markup::plain_text;
+
+/// Image shown on link previews, relative to the site URL.
+const SHARE_IMAGE: &str = "/public/og-image.png";
+
+/// Title, description, canonical URL and link preview cards for a page.
+#[component]
+pub fn PageMeta(
+    #[prop(into)] title: String,
+    #[prop(into)] description: String,
+    /// Path of the page (e.g. `/`), appended to the configured site URL.
+    #[prop(into)]
+    path: String,
+    /// Keeps the page out of search results, e.g. for private order links.
+    #[prop(optional)]
+    noindex: bool,
+) -> impl IntoView {
+    let site_url = use_app_config().site_url;
+    let url = format!("{site_url}{path}");
+    let image = format!("{site_url}{SHARE_IMAGE}");
+
+    view! {
+        <Title text=title.clone() />
+        <Meta name="description" content=description.clone() />
+        <Link rel="canonical" href=url.clone() />
+        {noindex.then(|| view! { <Meta name="robots" content="noindex, nofollow" /> })}
+
+        <Meta property="og:type" content="website" />
+        <Meta property="og:site_name" content="Untron" />
+        <Meta property="og:title" content=title.clone() />
+        <Meta property="og:description" content=description.clone() />
+        <Meta property="og:url" content=url />
+        <Meta property="og:image" content=image.clone() />
+
+        <Meta name="twitter:card" content="summary_large_image" />
+        <Meta name="twitter:title" content=title />
+        <Meta name="twitter:description" content=description />
+        <Meta name="twitter:image" content=image />
+    }
+}
+
+/// `FAQPage` structured data for the FAQ shown on the page, so search
+/// engines can show the answers as rich results.
+#[component]
+pub fn FaqStructuredData(#[prop(into)] items: Cow<'static, [FaqItem]>) -> impl IntoView {
+    view! { <Script type_="application/ld+json">{faq_page_json_ld(&items)}</Script> }
+}
+
+fn faq_page_json_ld(items: &[FaqItem]) -> String {
+    let questions = items
+        .iter()
+        .map(|item| {
+            json!({
+                "@type": "Question",
+                "name": item.question,
+                "acceptedAnswer": { "@type": "Answer", "text": plain_text(&item.answer) },
+            })
+        })
+        .collect::<Vec<_>>();
+    let data = json!({
+        "@context": "https://schema.org",
+        "@type": "FAQPage",
+        "mainEntity": questions,
+    });
+    // Script contents aren't escaped, so a `</script>` in an answer would end
+    // the tag early.
+    data.to_string().replace('<', "\\u003c")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::faq_accordion::DEFAULT_FAQ_ITEMS;
+
+    #[test]
+    fn faq_page_lists_every_question() {
+        let json: serde_json::Value =
+            serde_json::from_str(&faq_page_json_ld(DEFAULT_FAQ_ITEMS)).unwrap();
+        let questions = json["mainEntity"].as_array().unwrap();
+        assert_eq!(questions.len(), DEFAULT_FAQ_ITEMS.len());
+        assert_eq!(questions[0]["name"], DEFAULT_FAQ_ITEMS[0].question.as_ref());
+    }
+
+    #[test]
+    fn answers_are_plain_and_cannot_close_the_script() {
+        let items = [FaqItem {
+            slug: "a".into(),
+            question: "Q".into(),
+            answer: "**Bold** [link](https://untron.finance) </script>".into(),
+            emoji: None,
+        }];
+        let json = faq_page_json_ld(&items);
+        assert!(!json.contains("</script>"));
+        let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            json["mainEntity"][0]["acceptedAnswer"]["text"],
+            "Bold link </script>"
+        );
+    }
+}
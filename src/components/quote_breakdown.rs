@@ -0,0 +1,119 @@
+use leptos::prelude::*;
+
+use crate::config::content::use_content;
+use crate::utils::units::*;
+
+/// Id of the details panel, referenced by the toggle's `aria-controls`.
+const PANEL_ID: &str = "quote-breakdown";
+
+#[component]
+pub fn QuoteBreakdown(
+    /// Amount the user sends, as typed into the send input
+    #[prop(into)]
+    send_amount: Signal<String>,
+    /// Current swap rate (scaled by `RATE_SCALE`)
+    swap_rate_units: u64,
+    /// Name of the chain the recipient is paid on
+    #[prop(into)]
+    chain_name: Signal<String>,
+) -> impl IntoView {
+    let is_open = RwSignal::new(false);
+    let details = use_content().quote;
+    let detail_row = |label: &'static str, value: Option<std::borrow::Cow<'static, str>>| {
+        value.map(|value| {
+            view! {
+                <div class="flex justify-between">
+                    <dt>{label}</dt>
+                    <dd>{value}</dd>
+                </div>
+            }
+        })
+    };
+
+    // `None` while there's no valid amount to quote.
+    let send_units = move || string_to_units(&send_amount.get()).filter(|units| *units > 0);
+    let receive_units =
+        move || send_units().map(|units| convert_send_to_receive(units, swap_rate_units));
+    let protocol_fee_units = move || send_units().map(|units| protocol_fee(units, swap_rate_units));
+    let rate_label = move || {
+        format!(
+            "1 USDT Tron = {} USDT on {}",
+            units_to_string(swap_rate_units),
+            chain_name.get(),
+        )
+    };
+    let fee_label = move || {
+        format!(
+            "Protocol fee ({}%)",
+            units_to_string(RATE_SCALE.saturating_sub(swap_rate_units) * 100),
+        )
+    };
+
+    view! {
+        <Show when=move || send_units().is_some()>
+            <div class="bg-card rounded-[22px] px-6 py-[14px]">
+                <button
+                    type="button"
+                    class="w-full flex items-center justify-between text-left"
+                    aria-expanded=move || is_open.get().to_string()
+                    aria-controls=PANEL_ID
+                    on:click=move |_| is_open.update(|open| *open = !*open)
+                >
+                    <span class="text-base font-medium">
+                        {move || {
+                            format!(
+                                "You receive {} USDT on {}",
+                                units_to_string(receive_units().unwrap_or_default()),
+                                chain_name.get(),
+                            )
+                        }}
+                    </span>
+                    <span class=move || {
+                        let rotate = if is_open.get() { "rotate-180" } else { "rotate-0" };
+                        format!("transition-transform duration-100 w-[20px] h-[20px] inline-block {rotate}")
+                    }>
+                        <svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" stroke="currentColor">
+                            <path d="M6 10l6 6 6-6" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" />
+                        </svg>
+                    </span>
+                </button>
+                // Kept mounted so `aria-controls` always points at it.
+                <dl
+                    id=PANEL_ID
+                    hidden=move || !is_open.get()
+                    class="mt-3 space-y-1 text-base text-muted-foreground"
+                >
+                    <div class="flex justify-between">
+                        <dt>"Rate"</dt>
+                        <dd>{rate_label}</dd>
+                    </div>
+                    <div class="flex justify-between">
+                        <dt>{fee_label}</dt>
+                        <dd>
+                            {move || {
+                                format!(
+                                    "{} USDT",
+                                    units_to_string(protocol_fee_units().unwrap_or_default()),
+                                )
+                            }}
+                        </dd>
+                    </div>
+                    {detail_row("Network fee", details.network_fee.clone())}
+                    {detail_row("Estimated arrival", details.estimated_arrival.clone())}
+                    <div class="flex justify-between text-foreground font-medium">
+                        <dt>"You receive"</dt>
+                        <dd>
+                            {move || {
+                                format!(
+                                    "{} USDT on {}",
+                                    units_to_string(receive_units().unwrap_or_default()),
+                                    chain_name.get(),
+                                )
+                            }}
+                        </dd>
+                    </div>
+                </dl>
+            </div>
+        </Show>
+    }
+}
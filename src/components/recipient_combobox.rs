@@ -0,0 +1,155 @@
+use leptos::ev::KeyboardEvent;
+use leptos::prelude::*;
+
+use crate::address_book::{RecipientSuggestion, SuggestionSource};
+use crate::utils::units::units_to_string;
+
+/// Id of the suggestion list, referenced by the input's ARIA attributes.
+const LISTBOX_ID: &str = "recipient-suggestions";
+
+fn option_id(index: usize) -> String {
+    format!("recipient-suggestion-{index}")
+}
+
+// ---- Component ------------------------------------------------------------
+
+/// Destination address input that suggests known recipients. Follows the
+/// WAI-ARIA combobox pattern: the arrow keys move through the list, Enter
+/// picks the highlighted recipient and Escape closes the list.
+#[component]
+pub fn RecipientCombobox(
+    /// Text typed so far
+    value: RwSignal<String>,
+    /// Recipients to offer for the current text
+    #[prop(into)]
+    suggestions: Signal<Vec<RecipientSuggestion>>,
+    /// Called with the suggestion the user picked
+    #[prop(into)]
+    on_pick: Callback<RecipientSuggestion>,
+) -> impl IntoView {
+    let open = RwSignal::new(false);
+    // Highlighted suggestion, announced through `aria-activedescendant`.
+    let active = RwSignal::new(None::<usize>);
+    let expanded = move || open.get() && suggestions.with(|s| !s.is_empty());
+    // The list changes while typing; start again from the top.
+    Effect::new(move |_| {
+        suggestions.track();
+        active.set(None);
+    });
+
+    let pick = move |index: usize| {
+        if let Some(suggestion) = suggestions.with_untracked(|s| s.get(index).cloned()) {
+            open.set(false);
+            on_pick.run(suggestion);
+        }
+    };
+
+    let handle_keydown = move |ev: KeyboardEvent| {
+        let count = suggestions.with_untracked(Vec::len);
+        match ev.key().as_str() {
+            key @ ("ArrowDown" | "ArrowUp") if count > 0 => {
+                ev.prevent_default();
+                open.set(true);
+                let down = key == "ArrowDown";
+                active.update(|active| {
+                    *active = Some(match (*active, down) {
+                        (None, true) => 0,
+                        (None, false) => count - 1,
+                        (Some(i), true) => (i + 1) % count,
+                        (Some(i), false) => (i + count - 1) % count,
+                    })
+                });
+            }
+            "Enter" => {
+                if let Some(index) = active.get_untracked().filter(|_| open.get_untracked()) {
+                    ev.prevent_default();
+                    pick(index);
+                }
+            }
+            "Escape" => {
+                open.set(false);
+                active.set(None);
+            }
+            _ => {}
+        }
+    };
+
+    // Render ----------------------------------------------------------------
+    view! {
+        <div class="relative w-full">
+            <input
+                type="text"
+                role="combobox"
+                aria-label="Recipient address"
+                aria-autocomplete="list"
+                aria-controls=LISTBOX_ID
+                aria-expanded=move || expanded().to_string()
+                aria-activedescendant=move || active.get().filter(|_| expanded()).map(option_id)
+                autocomplete="off"
+                class="w-full outline-none text-black text-lg font-medium bg-transparent"
+                prop:value=value
+                on:input:target=move |ev| {
+                    value.set(ev.target().value());
+                    open.set(true);
+                }
+                on:focus=move |_| open.set(true)
+                on:blur=move |_| open.set(false)
+                on:keydown=handle_keydown
+                placeholder="ENS or Address"
+            />
+            // Kept mounted so `aria-controls` always points at it.
+            <ul
+                id=LISTBOX_ID
+                hidden=move || !expanded()
+                role="listbox"
+                aria-label="Suggested recipients"
+                class="absolute left-0 right-0 top-full mt-3 z-10 bg-white rounded-[16px] shadow-lg py-2"
+            >
+                {move || {
+                    suggestions
+                        .get()
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, suggestion)| {
+                            let is_active = move || active.get() == Some(index);
+                            view! {
+                                <li
+                                    id=option_id(index)
+                                    role="option"
+                                    aria-selected=move || is_active().to_string()
+                                    class=move || {
+                                        let bg = if is_active() { "bg-[#f5f5f7]" } else { "" };
+                                        format!("px-4 py-2 cursor-pointer {bg}")
+                                    }
+                                    // Keep the focus in the input so `blur` doesn't close the list first.
+                                    on:mousedown=|ev| ev.prevent_default()
+                                    on:click=move |_| pick(index)
+                                >
+                                    <SuggestionLabel suggestion />
+                                </li>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </ul>
+        </div>
+    }
+}
+
+#[component]
+fn SuggestionLabel(suggestion: RecipientSuggestion) -> impl IntoView {
+    let title = match suggestion.source {
+        SuggestionSource::Wallet => "Your wallet".to_string(),
+        SuggestionSource::Saved { label } => label,
+        SuggestionSource::Recent => "Recent recipient".to_string(),
+    };
+    let last_sent = suggestion
+        .send_units
+        .map(|units| format!("Last sent {} USDT", units_to_string(units)));
+
+    view! {
+        <div class="font-medium text-black">{title}</div>
+        <div class="font-mono text-sm text-muted-foreground break-all">{suggestion.address}</div>
+        {last_sent.map(|text| view! { <div class="text-sm text-muted-foreground">{text}</div> })}
+    }
+}
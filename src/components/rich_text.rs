@@ -0,0 +1,52 @@
+use leptos::prelude::*;
+
+use crate::utils::markup::{parse, Block, Inline};
+
+/// Renders a [`crate::utils::markup`] source string as views.
+#[component]
+pub fn RichText(#[prop(into)] source: String) -> impl IntoView {
+    parse(&source)
+        .into_iter()
+        .map(|block| match block {
+            Block::Paragraph(inlines) => {
+                view! { <p class="[&:not(:first-child)]:mt-2">{render_inlines(inlines)}</p> }
+                    .into_any()
+            }
+            Block::List(items) => view! {
+                <ul class="list-disc pl-5 [&:not(:first-child)]:mt-2">
+                    {items
+                        .into_iter()
+                        .map(|item| view! { <li>{render_inlines(item)}</li> })
+                        .collect::<Vec<_>>()}
+                </ul>
+            }
+            .into_any(),
+        })
+        .collect::<Vec<_>>()
+}
+
+fn render_inlines(inlines: Vec<Inline>) -> Vec<AnyView> {
+    inlines
+        .into_iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => text.into_any(),
+            Inline::Bold(inner) => {
+                view! { <strong class="font-semibold">{render_inlines(inner)}</strong> }.into_any()
+            }
+            Inline::Link { text, href } if href.starts_with("mailto:") => {
+                view! { <a href=href class="text-primary hover:underline">{text}</a> }.into_any()
+            }
+            Inline::Link { text, href } => view! {
+                <a
+                    href=href
+                    target="_blank"
+                    rel="noopener noreferrer"
+                    class="text-primary hover:underline"
+                >
+                    {text}
+                </a>
+            }
+            .into_any(),
+        })
+        .collect()
+}
@@ -0,0 +1,622 @@
+use leptos::prelude::*;
+use std::time::Duration;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+
+// Local imports
+use crate::address_book::{
+    recipient_suggestions, use_address_book, RecentRecipient, RecipientSuggestion, SavedRecipient,
+};
+use crate::api::info::{fetch_info, InfoState, PrefetchedInfo};
+use crate::api::order::{create_order, CreateOrderRequest};
+use crate::api::{ApiError, Cancellation, ErrorField};
+use crate::components::{
+    address_chunks::AddressChunks, currency_input::CurrencyInput, quote_breakdown::QuoteBreakdown,
+    recipient_combobox::RecipientCombobox,
+};
+use crate::config::app::use_app_config;
+use crate::i18n::{api_error_message, use_locale};
+use crate::utils::address::{is_valid_evm_address, short_address};
+use crate::utils::quote::{Quote, QuoteSide};
+use crate::utils::swap_link::SwapLink;
+use crate::utils::time::now_secs;
+use crate::utils::units::*;
+use crate::wallet::use_tron_wallet;
+
+// ---------------- Constants ----------------
+/// How often `/info` is refetched so the maximum order size stays current.
+const INFO_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+/// Recipients suggested while typing the address.
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Idempotency slot of the swap form's order submissions.
+const ORDER_SLOT: &str = "swap";
+
+/// The swap form: both amounts, the recipient and the swap button. Shown on
+/// the landing page and mounted by partners through the embeddable build.
+#[component]
+pub fn UntronWidget(
+    /// Values the form starts with.
+    #[prop(optional)]
+    initial: SwapLink,
+    /// Keeps the recipient at `initial.to`, e.g. a merchant's own address.
+    #[prop(optional)]
+    fixed_recipient: bool,
+    /// Fixes the amount received (in units), e.g. for a payment request. The
+    /// amount sent is derived from it and neither can be edited.
+    #[prop(optional)]
+    fixed_receive_units: Option<u64>,
+    /// Called with the form's values whenever they change.
+    #[prop(optional, into)]
+    on_change: Option<Callback<SwapLink>>,
+    /// Called with the id of every order created.
+    #[prop(into)]
+    on_order_created: Callback<String>,
+) -> impl IntoView {
+    // ---------------------- STATE ----------------------
+    let app_config = use_app_config();
+    // Amounts to send / receive. The side edited last is authoritative; the
+    // other one is derived from it (see `Quote`).
+    let quote = RwSignal::new(match fixed_receive_units {
+        Some(units) => Quote {
+            side: QuoteSide::Receive,
+            input: units_to_string(units),
+            clamped: false,
+        },
+        None => initial
+            .amount
+            .as_deref()
+            .and_then(|amount| Quote::edit(QuoteSide::Send, amount, SWAP_RATE_UNITS, None))
+            .unwrap_or_default(),
+    });
+    let read_only = fixed_receive_units.is_some();
+    let send_amount =
+        Signal::derive(move || quote.with(|q| q.text(QuoteSide::Send, SWAP_RATE_UNITS)));
+    let send_units =
+        Signal::derive(move || quote.with(|q| q.units(QuoteSide::Send, SWAP_RATE_UNITS)));
+
+    // Address badge vs. free-text input.
+    let (address_badge, set_address_badge) = signal(initial.to);
+    let input_value = RwSignal::new(String::new());
+    // Where orders pay out to: the badge, or a complete address typed as text.
+    // Both are checked, so no route can put an invalid address into an order.
+    let recipient = Signal::derive(move || {
+        let badge = address_badge.get().filter(|to| is_valid_evm_address(to));
+        badge.or_else(|| {
+            let typed = input_value.with(|text| text.trim().to_string());
+            is_valid_evm_address(&typed).then_some(typed)
+        })
+    });
+
+    let locale = use_locale();
+    let features = app_config.features;
+    let default_chain = app_config.default_chain().id;
+    let chain_ids = StoredValue::new(app_config.chains.iter().map(|c| c.id).collect::<Vec<_>>());
+    let to_chain = RwSignal::new(initial.chain.unwrap_or(default_chain));
+    // Links and saved recipients can pick another chain, so it's always named.
+    let to_chain_name = {
+        let chains = app_config.chains.clone();
+        Signal::derive(move || {
+            let id = to_chain.get();
+            chains
+                .iter()
+                .find(|c| c.id == id)
+                .map_or_else(|| id.to_string(), |c| c.name.clone())
+        })
+    };
+    // A fixed recipient isn't picked by the user, so there's nothing to save.
+    let address_book = use_address_book().filter(|_| !fixed_recipient);
+    let api_base_url = StoredValue::new(app_config.api_base_url);
+    let is_swapping = RwSignal::new(false);
+    let error_message = RwSignal::new(None::<String>);
+    // Server-side validation error pointing at a specific input.
+    let field_error = RwSignal::new(None::<(ErrorField, String)>);
+    let error_for = move |field: ErrorField| {
+        Signal::derive(move || {
+            field_error
+                .get()
+                .filter(|(f, _)| *f == field)
+                .map(|(_, msg)| msg)
+        })
+    };
+    let amount_error = error_for(ErrorField::FromAmount);
+    let beneficiary_error = error_for(ErrorField::Beneficiary);
+
+    // Aborts in-flight requests when the widget unmounts.
+    let cancellation = Cancellation::on_cleanup();
+
+    // Liquidity comes from /info, refetched periodically. Until the first
+    // response arrives (or while it keeps failing) orders can't be created.
+    let info = {
+        let cancellation = cancellation.clone();
+        LocalResource::new(move || fetch_info(api_base_url.get_value(), cancellation.clone()))
+    };
+    // Fetched by the server while rendering (when enabled), so liquidity shows
+    // up before the first client request completes.
+    let prefetched_info = use_context::<PrefetchedInfo>().unwrap_or_default().0;
+    let info_state = Signal::derive(move || {
+        // `info` never loads on the server; reading it there would hold the
+        // whole page back behind its `Suspense`.
+        let fetched = if cfg!(feature = "ssr") {
+            None
+        } else {
+            info.get()
+        };
+        InfoState::from_resource(fetched.or_else(|| prefetched_info.clone().map(Ok)))
+    });
+    let max_order_output = Signal::derive(move || info_state.get().max_units());
+    let min_order_output = Signal::derive(move || info_state.get().min_units());
+    let is_below_min = Signal::derive(move || {
+        let min = min_order_output.get().unwrap_or(0);
+        quote
+            .with(|q| q.units(QuoteSide::Receive, SWAP_RATE_UNITS))
+            .is_some_and(|units| units < min)
+    });
+    // Edits are clamped to the maximum, but a fixed amount can't be.
+    let is_above_max = Signal::derive(move || {
+        let receive = quote.with(|q| q.units(QuoteSide::Receive, SWAP_RATE_UNITS));
+        matches!((receive, max_order_output.get()), (Some(units), Some(max)) if units > max)
+    });
+    // Largest sendable amount: bounded by liquidity and, once known, by the
+    // connected wallet's balance.
+    let wallet = use_tron_wallet();
+    let max_send_units = Signal::derive(move || {
+        let by_liquidity = max_order_output
+            .get()
+            .map(|max| max_send_for_receive(max, SWAP_RATE_UNITS));
+        match (by_liquidity, wallet.usdt_balance.get()) {
+            (Some(liquidity), Some(balance)) => Some(liquidity.min(balance)),
+            (liquidity, balance) => liquidity.or(balance),
+        }
+    });
+    // Effects only run in the browser, so the server never starts a timer.
+    Effect::new(move |_| {
+        if let Ok(handle) = set_interval_with_handle(move || info.refetch(), INFO_REFRESH_INTERVAL)
+        {
+            on_cleanup(move || handle.clear());
+        }
+    });
+
+    // A field error is stale as soon as the user edits the form.
+    Effect::new(move |_| {
+        quote.track();
+        recipient.track();
+        field_error.set(None);
+    });
+
+    if let Some(on_change) = on_change {
+        Effect::new(move |_| {
+            on_change.run(SwapLink {
+                amount: send_units.get().map(units_to_string),
+                to: recipient.get(),
+                chain: Some(to_chain.get()).filter(|&chain| chain != default_chain),
+            });
+        });
+    }
+
+    // -------- Clipboard / paste handling ---------------
+    // A pasted address becomes a badge; anything else lands in the input, so
+    // the user sees what was pasted.
+    let on_paste = move |_| {
+        let read = window().navigator().clipboard().read_text();
+        spawn_local(async move {
+            let Some(text) = JsFuture::from(read).await.ok().and_then(|t| t.as_string()) else {
+                return;
+            };
+            let text = text.trim().to_string();
+            if is_valid_evm_address(&text) {
+                input_value.set(String::new());
+                set_address_badge.set(Some(text));
+            } else {
+                input_value.set(text);
+            }
+        });
+    };
+
+    // Clear the badge and return to input mode.
+    let clear_badge = move |_| {
+        set_address_badge.set(None);
+    };
+
+    // ---------------- Address book ---------------------
+    let suggestions = Signal::derive(move || {
+        recipient_suggestions(
+            wallet.evm_address.get().as_deref(),
+            address_book,
+            &input_value.get(),
+            MAX_SUGGESTIONS,
+        )
+    });
+    let pick_suggestion = move |suggestion: RecipientSuggestion| {
+        if let Some(chain) = suggestion
+            .chain
+            .filter(|chain| chain_ids.read_value().contains(chain))
+        {
+            to_chain.set(chain);
+        }
+        // Repeat the last amount sent, unless one was entered already.
+        if let Some(units) = suggestion.send_units.filter(|_| !read_only) {
+            if quote.with_untracked(|q| q.input.is_empty()) {
+                let amount = units_to_string(units);
+                let max = max_order_output.get_untracked();
+                if let Some(edited) = Quote::edit(QuoteSide::Send, &amount, SWAP_RATE_UNITS, max) {
+                    quote.set(edited);
+                }
+            }
+        }
+        set_address_badge.set(Some(suggestion.address));
+        input_value.set(String::new());
+    };
+    // A known recipient the entered address imitates (address poisoning).
+    let lookalike_of = move || {
+        let address = recipient.get()?;
+        address_book?
+            .lookalike_of(&address)
+            .map(|known| (address, known))
+    };
+    let saved_label = move || {
+        let address = recipient.get()?;
+        address_book?.find(&address).map(|r| r.label)
+    };
+    // Label being typed for the recipient's address, while saving it.
+    let new_label = RwSignal::new(None::<String>);
+    // The badge is shortened; this shows the whole address, grouped.
+    let show_full_address = RwSignal::new(false);
+    Effect::new(move |_| {
+        recipient.track();
+        new_label.set(None);
+        show_full_address.set(false);
+    });
+    let save_recipient = move || {
+        if let (Some(book), Some(address), Some(label)) =
+            (address_book, recipient.get(), new_label.get())
+        {
+            let chain = Some(to_chain.get()).filter(|&chain| chain != default_chain);
+            book.save(SavedRecipient {
+                chain,
+                ..SavedRecipient::new(&label, &address)
+            });
+            new_label.set(None);
+        }
+    };
+
+    // ------- Derived UI fragments -------
+    let address_view = move || {
+        view! {
+            <Show
+                when=move || address_badge.get().is_some()
+                fallback=move || {
+                    view! {
+                        <RecipientCombobox
+                            value=input_value
+                            suggestions
+                            on_pick=pick_suggestion
+                        />
+                    }
+                }
+            >
+                <div class="bg-black text-white text-base font-medium px-4 py-1.5 rounded-full flex items-center">
+                    {move || saved_label().map(|label| view! { <span class="mr-2">{label}</span> })}
+                    <span title=move || address_badge.get()>
+                        {move || address_badge.get().as_deref().map(short_address)}
+                    </span>
+                    <button
+                        type="button"
+                        class="ml-2 text-sm underline"
+                        aria-expanded=move || show_full_address.get().to_string()
+                        on:click=move |_| show_full_address.update(|shown| *shown = !*shown)
+                    >
+                        {move || if show_full_address.get() { "Hide" } else { "Show all" }}
+                    </button>
+                    {(!fixed_recipient)
+                        .then(|| {
+                            view! {
+                                <button on:click=clear_badge class="ml-2 text-lg leading-none">
+                                    "×"
+                                </button>
+                            }
+                        })}
+                </div>
+            </Show>
+        }.into_view()
+    };
+
+    // Saving the recipient's address, and a link to the saved ones.
+    let address_book_view = move || {
+        address_book?;
+        let save_form = move || {
+            view! {
+                <form
+                    class="flex items-center gap-2"
+                    on:submit=move |ev| {
+                        ev.prevent_default();
+                        save_recipient();
+                    }
+                >
+                    <input
+                        type="text"
+                        class="flex-1 bg-white rounded-full px-4 py-1.5 outline-none"
+                        placeholder="Label, e.g. Alice"
+                        aria-label="Label"
+                        prop:value=move || new_label.get().unwrap_or_default()
+                        on:input:target=move |ev| new_label.set(Some(ev.target().value()))
+                    />
+                    <button
+                        type="submit"
+                        class="bg-black text-white text-base font-medium px-4 py-1.5 rounded-full"
+                    >
+                        "Save"
+                    </button>
+                    <button type="button" class="text-base" on:click=move |_| new_label.set(None)>
+                        "Cancel"
+                    </button>
+                </form>
+            }
+        };
+        Some(view! {
+            <div class="pl-[16px] space-y-1">
+                <Show when=move || recipient.get().is_some() && saved_label().is_none()>
+                    <Show
+                        when=move || new_label.with(Option::is_some)
+                        fallback=move || {
+                            view! {
+                                <button
+                                    type="button"
+                                    class="text-sm underline"
+                                    on:click=move |_| new_label.set(Some(String::new()))
+                                >
+                                    "Save to address book"
+                                </button>
+                            }
+                        }
+                    >
+                        {save_form}
+                    </Show>
+                </Show>
+                <a href="/address-book" class="block text-sm text-muted-foreground underline">
+                    "Manage address book"
+                </a>
+            </div>
+        })
+    };
+
+    let badge_button_view = move || {
+        view! {
+            <Show
+                when=move || address_badge.get().is_some()
+                fallback=move || {
+                    view! {
+                        <button
+                            class="bg-black text-white text-base font-medium px-4 py-1.5 rounded-full"
+                            on:click=on_paste
+                        >
+                            "Paste"
+                        </button>
+                    }
+                }
+            >
+                <button
+                    class="bg-black text-white text-base font-medium px-4 py-1.5 rounded-full"
+                    on:click=clear_badge
+                >
+                    "Other"
+                </button>
+            </Show>
+        }
+        .into_view()
+    };
+
+    // ---------------- Swap button behaviour ------------
+    let can_swap = move || {
+        features.orders
+            && !is_swapping.get()
+            && recipient.get().is_some()
+            && send_units.get().is_some()
+            && info_state.get().is_ready()
+            && !is_below_min.get()
+            && !is_above_max.get()
+    };
+
+    let handle_swap = move |_| {
+        if !can_swap() {
+            return;
+        }
+        is_swapping.set(true);
+        error_message.set(None);
+        let beneficiary = recipient.get().unwrap();
+        let from_units = send_units.get().unwrap();
+        let to_chain = to_chain.get_untracked();
+        let is_swapping_flag = is_swapping;
+        let error_message_flag = error_message;
+        let cancellation = cancellation.clone();
+        spawn_local(async move {
+            let order = CreateOrderRequest {
+                to_coin: "usdt",
+                to_chain,
+                from_amount: from_units,
+                rate: SWAP_RATE_UNITS,
+                beneficiary: beneficiary.clone(),
+            };
+
+            match create_order(&api_base_url.get_value(), &order, ORDER_SLOT, &cancellation).await {
+                Ok(json) => {
+                    if let Some(book) = address_book {
+                        book.record_order(RecentRecipient {
+                            address: beneficiary,
+                            chain: to_chain,
+                            send_units: from_units,
+                            at: now_secs(),
+                        });
+                    }
+                    on_order_created.run(json.id);
+                }
+                // The widget is gone; there's nobody left to notify.
+                Err(ApiError::Cancelled) => {}
+                Err(err) => {
+                    let message = api_error_message(&err, locale);
+                    match err.server_error().and_then(|e| e.field) {
+                        Some(field @ (ErrorField::FromAmount | ErrorField::Beneficiary)) => {
+                            field_error.set(Some((field, message)));
+                        }
+                        _ => error_message_flag.set(Some(message)),
+                    }
+                    is_swapping_flag.set(false);
+                }
+            }
+        });
+    };
+
+    view! {
+        <div class="space-y-4">
+            // ---------------- Currency Inputs --------------
+            <CurrencyInput
+                label="You send"
+                currency_icon="/public/USDTtron.svg"
+                currency_name="USDT Tron"
+                side=QuoteSide::Send
+                quote=quote
+                max_units=max_order_output
+                min_units=min_order_output
+                swap_rate_units=SWAP_RATE_UNITS
+                error=amount_error
+                max_fill_units=max_send_units
+                read_only=read_only
+            />
+
+            <CurrencyInput
+                label="You receive"
+                currency_icon="/public/USDTarb.svg"
+                currency_name=Signal::derive(move || format!("USDT on {}", to_chain_name.get()))
+                side=QuoteSide::Receive
+                quote=quote
+                max_units=max_order_output
+                swap_rate_units=SWAP_RATE_UNITS
+                min_units=min_order_output
+                show_max_output=true
+                show_min_output=true
+                read_only=read_only
+            />
+
+            <QuoteBreakdown
+                send_amount=send_amount
+                swap_rate_units=SWAP_RATE_UNITS
+                chain_name=to_chain_name
+            />
+
+            // ---------------- Destination Address ---------
+            <div class=move || {
+                let ring = if beneficiary_error.get().is_some() {
+                    "ring-2 ring-red-500"
+                } else {
+                    ""
+                };
+                format!("bg-white rounded-[22px] py-[14px] flex items-center {ring}")
+            }>
+                <div class="flex-1 flex items-center pl-[16px]">
+                    <div class="flex items-center w-full">
+                        <span class="text-lg font-regular text-[#000000] mr-2">"To"</span>
+                        {address_view()}
+                    </div>
+                </div>
+
+                {(!fixed_recipient)
+                    .then(|| view! { <div class="pr-[10px]">{badge_button_view()}</div> })}
+            </div>
+            {move || {
+                beneficiary_error
+                    .get()
+                    .map(|err| view! { <p class="text-sm text-red-500 pl-[16px]">{err}</p> })
+            }}
+            {move || {
+                address_badge
+                    .get()
+                    .filter(|_| show_full_address.get())
+                    .map(|address| {
+                        view! {
+                            <p class="text-base pl-[16px]">
+                                <AddressChunks address />
+                            </p>
+                        }
+                    })
+            }}
+            {move || {
+                lookalike_of()
+                    .map(|(address, known)| {
+                        let name = known
+                            .label
+                            .map_or_else(|| "someone you sent to before".to_string(), |label| {
+                                format!("\"{label}\" in your address book")
+                            });
+                        view! {
+                            <div role="alert" class="text-sm text-red-500 pl-[16px] space-y-1">
+                                <p>
+                                    "Careful: this address starts and ends like the one of " {name}
+                                    ", but it's a different address. Scammers send from look-alike \
+                                     addresses so they show up in your history. Compare the \
+                                     highlighted groups before paying."
+                                </p>
+                                <p>
+                                    "This address: "
+                                    <AddressChunks address=address compare_to=known.address.clone() />
+                                </p>
+                                <p>
+                                    "Known address: " <AddressChunks address=known.address.clone() />
+                                </p>
+                            </div>
+                        }
+                    })
+            }}
+            {address_book_view}
+
+            // ---------------- Swap button -----------------
+            <button
+                class="w-full py-4 rounded-[22px] text-[24px] font-medium bg-black text-white transition-colors"
+                on:click=handle_swap
+                disabled=move || !can_swap()
+            >
+                <Show when=move || is_swapping.get() fallback=|| view! { "Untron!" }>
+                    {"Processing…"}
+                </Show>
+            </button>
+
+            {move || {
+                error_message
+                    .get()
+                    .map(|err| {
+                        view! {
+                            <p class="text-center text-red-500 mt-2 text-base">{err}</p>
+                        }
+                            .into_any()
+                    })
+                    .unwrap_or_else(|| {
+                        let status = match info_state.get() {
+                            _ if !features.orders => {
+                                "Swaps are paused for maintenance. Please check back soon."
+                                    .to_string()
+                            }
+                            InfoState::Loading => "Checking available liquidity…".to_string(),
+                            InfoState::Unavailable(err) => {
+                                format!(
+                                    "Couldn't load available liquidity ({}). Retrying shortly…",
+                                    api_error_message(&err, locale),
+                                )
+                            }
+                            InfoState::Ready(_) if is_above_max.get() => {
+                                format!(
+                                    "This amount is above the current maximum of {} USDT. Please try again later.",
+                                    units_to_string(max_order_output.get().unwrap_or_default()),
+                                )
+                            }
+                            InfoState::Ready(_) => String::new(),
+                        };
+                        view! {
+                            <p class="text-center text-muted-foreground mt-2 text-base">
+                                {status}
+                            </p>
+                        }
+                            .into_any()
+                    })
+            }}
+
+        </div>
+    }
+}
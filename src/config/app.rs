@@ -0,0 +1,193 @@
+//! Deployment-specific settings: which backend to talk to, which chains to
+//! offer and which features to enable.
+//!
+//! Values are resolved in order, later ones winning:
+//! 1. the compiled defaults (production),
+//! 2. `config.json`, fetched from next to the bundle on startup (or read from
+//!    the working directory by the SSR server). Fields it leaves out keep
+//!    their default,
+//! 3. `UNTRON_API_BASE_URL` at build time, so a local build can point at a
+//!    local backend whatever the shipped `config.json` says.
+
+use std::future::Future;
+
+use leptos::logging;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Where the runtime config is served from (copied there by Trunk).
+#[cfg(not(feature = "ssr"))]
+const CONFIG_URL: &str = "/config.json";
+
+/// Where the SSR server reads the runtime config from.
+#[cfg(feature = "ssr")]
+pub(crate) const CONFIG_FILE: &str = "config.json";
+
+const DEFAULT_SITE_URL: &str = "https://untron.finance";
+const DEFAULT_API_BASE_URL: &str = "https://untron.finance/api/v2-public";
+
+/// API base URL baked in at build time; overrides every other source.
+const BUILD_API_BASE_URL: Option<&str> = option_env!("UNTRON_API_BASE_URL");
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct AppConfig {
+    /// Public origin of the site, without a trailing slash; canonical and
+    /// link preview URLs are built from it.
+    pub site_url: String,
+    /// Base URL of the Untron API, without a trailing slash.
+    pub api_base_url: String,
+    /// Chains USDT can be received on; the first one is the default.
+    pub chains: Vec<ChainConfig>,
+    pub features: Features,
+}
+
+/// A destination chain.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ChainConfig {
+    /// EVM chain id, as sent to the API.
+    pub id: u32,
+    pub name: String,
+}
+
+/// Switches for turning parts of the app off without a redeploy.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct Features {
+    /// Whether new orders can be created (off during maintenance).
+    pub orders: bool,
+    /// Whether the FAQ shows a search box.
+    pub faq_search: bool,
+    /// Whether the SSR server fetches `/info` while rendering the page.
+    pub prefetch_info: bool,
+}
+
+impl Default for Features {
+    fn default() -> Self {
+        Self {
+            orders: true,
+            faq_search: true,
+            prefetch_info: true,
+        }
+    }
+}
+
+impl Default for AppConfig {
+    /// Production settings, with `UNTRON_API_BASE_URL` overriding the API URL
+    /// when set at compile time.
+    fn default() -> Self {
+        Self {
+            site_url: DEFAULT_SITE_URL.to_string(),
+            api_base_url: BUILD_API_BASE_URL
+                .unwrap_or(DEFAULT_API_BASE_URL)
+                .to_string(),
+            chains: vec![ChainConfig {
+                id: 42161,
+                name: "Arbitrum One".to_string(),
+            }],
+            features: Features::default(),
+        }
+    }
+}
+
+impl AppConfig {
+    /// Decodes and validates a config file on top of the defaults.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let mut config: Self = serde_json::from_str(json).map_err(|err| err.to_string())?;
+        config.site_url = config.site_url.trim_end_matches('/').to_string();
+        config.api_base_url = config.api_base_url.trim_end_matches('/').to_string();
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let is_http = |url: &str| url.starts_with("https://") || url.starts_with("http://");
+        if !is_http(&self.site_url) {
+            return Err(format!("siteUrl {:?} isn't a URL", self.site_url));
+        }
+        // A relative base lets a dev server proxy the API on the same origin.
+        if !(is_http(&self.api_base_url) || self.api_base_url.starts_with('/')) {
+            return Err(format!("apiBaseUrl {:?} isn't a URL", self.api_base_url));
+        }
+        if self.chains.is_empty() {
+            return Err("no chains configured".to_string());
+        }
+        Ok(())
+    }
+
+    /// Chain orders are sent to unless the user picks another one.
+    pub fn default_chain(&self) -> &ChainConfig {
+        self.chains.first().expect("validated config has a chain")
+    }
+}
+
+/// Loads the config, falling back to the compiled defaults when it's missing
+/// or invalid: from disk on the server, over HTTP in the browser.
+pub fn load_app_config() -> impl Future<Output = AppConfig> + Send + 'static {
+    #[cfg(feature = "ssr")]
+    let (source, json) = (CONFIG_FILE, async {
+        tokio::fs::read_to_string(CONFIG_FILE)
+            .await
+            .map_err(|err| err.to_string())
+    });
+    #[cfg(not(feature = "ssr"))]
+    let (source, json) = (
+        CONFIG_URL,
+        send_wrapper::SendWrapper::new(async {
+            crate::api::request::ApiRequest::get(CONFIG_URL)
+                .timeout(super::FILE_TIMEOUT)
+                .retries(super::FILE_RETRIES)
+                .send_text()
+                .await
+                .map_err(|err| format!("{err:?}"))
+        }),
+    );
+
+    async move {
+        match json.await.and_then(|json| AppConfig::from_json(&json)) {
+            Ok(config) => AppConfig {
+                api_base_url: BUILD_API_BASE_URL.map_or(config.api_base_url, str::to_string),
+                ..config
+            },
+            Err(err) => {
+                logging::warn!("{source}: {err}; using built-in config");
+                AppConfig::default()
+            }
+        }
+    }
+}
+
+/// Current config, as provided by `App` before any page renders.
+pub fn use_app_config() -> AppConfig {
+    use_context::<AppConfig>().expect("AppConfig is provided by App")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_config_is_valid() {
+        AppConfig::from_json(include_str!("../../config.json")).unwrap();
+    }
+
+    #[test]
+    fn missing_fields_keep_defaults() {
+        let config = AppConfig::from_json(
+            r#"{ "apiBaseUrl": "http://localhost:8080/api/", "features": { "orders": false } }"#,
+        )
+        .unwrap();
+        assert_eq!(config.api_base_url, "http://localhost:8080/api");
+        assert!(!config.features.orders);
+        assert!(config.features.faq_search);
+        assert_eq!(config.chains, AppConfig::default().chains);
+    }
+
+    #[test]
+    fn invalid_config_is_rejected() {
+        assert!(AppConfig::from_json(r#"{ "chains": [] }"#).is_err());
+        assert!(AppConfig::from_json(r#"{ "apiBaseUrl": "untron.finance" }"#).is_err());
+        assert!(AppConfig::from_json(r#"{ "apiBaseUrl": "/api", "typo": 1 }"#).is_err());
+    }
+}
@@ -0,0 +1,279 @@
+//! Site copy (FAQ, footer and quote details) that can change without a
+//! rebuild.
+//!
+//! `public/content/<locale>.json` is loaded on startup (fetched by the
+//! browser, or read from disk by the SSR server). If it's missing or fails
+//! validation, the compiled defaults from [`super::faq_accordion`] and
+//! [`super::footer`] are shown instead.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::fmt;
+use std::future::Future;
+
+use leptos::logging;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::faq_accordion::DEFAULT_FAQ_ITEMS;
+use super::footer::{FooterSection, FOOTER_LINKS};
+use crate::components::FaqItem;
+use crate::i18n::Locale;
+
+/// Everything a content file holds.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SiteContent {
+    pub faq: Cow<'static, [FaqItem]>,
+    pub footer: Cow<'static, [FooterSection]>,
+    #[serde(default)]
+    pub quote: QuoteDetails,
+}
+
+/// Extra rows of the quote breakdown. The backend doesn't report these, so
+/// they're only shown when a content file states them.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct QuoteDetails {
+    /// Network fee the recipient pays, e.g. "Free".
+    pub network_fee: Option<Cow<'static, str>>,
+    /// How long payouts usually take, e.g. "~1 minute".
+    pub estimated_arrival: Option<Cow<'static, str>>,
+}
+
+/// Why a content file was rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ContentError {
+    /// Not valid JSON, or doesn't match the schema.
+    Decode(String),
+    /// Well-formed, but breaks a rule `validate` enforces.
+    Invalid(String),
+}
+
+impl fmt::Display for ContentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Decode(err) => write!(f, "couldn't decode content: {err}"),
+            Self::Invalid(err) => write!(f, "invalid content: {err}"),
+        }
+    }
+}
+
+impl Default for SiteContent {
+    /// The compiled-in (English) content.
+    fn default() -> Self {
+        Self {
+            faq: Cow::Borrowed(DEFAULT_FAQ_ITEMS),
+            footer: Cow::Borrowed(FOOTER_LINKS),
+            quote: QuoteDetails::default(),
+        }
+    }
+}
+
+impl SiteContent {
+    /// Decodes and validates a content file.
+    pub fn from_json(json: &str) -> Result<Self, ContentError> {
+        let content: Self =
+            serde_json::from_str(json).map_err(|err| ContentError::Decode(err.to_string()))?;
+        content.validate()?;
+        Ok(content)
+    }
+
+    /// Checks the rules serde can't express: nothing blank, slugs usable in
+    /// URL fragments and unique, footer links `https://` or `mailto:`.
+    pub fn validate(&self) -> Result<(), ContentError> {
+        let invalid = |msg: String| Err(ContentError::Invalid(msg));
+
+        if self.faq.is_empty() {
+            return invalid("no FAQ items".into());
+        }
+        let mut slugs = HashSet::new();
+        for item in self.faq.iter() {
+            let slug = item.slug.as_ref();
+            if slug.is_empty()
+                || !slug
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+            {
+                return invalid(format!("FAQ slug {slug:?} isn't lowercase kebab-case"));
+            }
+            if !slugs.insert(slug) {
+                return invalid(format!("duplicate FAQ slug {slug:?}"));
+            }
+            if item.question.trim().is_empty() || item.answer.trim().is_empty() {
+                return invalid(format!("FAQ item {slug:?} has a blank question or answer"));
+            }
+        }
+
+        for section in self.footer.iter() {
+            if section.title.trim().is_empty() || section.links.is_empty() {
+                return invalid("footer section without a title or links".into());
+            }
+            for link in section.links.iter() {
+                if link.text.trim().is_empty() {
+                    return invalid(format!("footer link {:?} has no text", link.href));
+                }
+                if !(link.href.starts_with("https://") || link.href.starts_with("mailto:")) {
+                    return invalid(format!("footer link {:?} isn't https or mailto", link.href));
+                }
+            }
+        }
+
+        let QuoteDetails {
+            network_fee,
+            estimated_arrival,
+        } = &self.quote;
+        if [network_fee, estimated_arrival]
+            .into_iter()
+            .flatten()
+            .any(|text| text.trim().is_empty())
+        {
+            return invalid("blank quote detail".into());
+        }
+        Ok(())
+    }
+}
+
+/// Where the content file for `locale` is served from, relative to the site
+/// root (and, for the SSR server, to its working directory).
+fn content_path(locale: Locale) -> String {
+    format!("public/content/{}.json", locale.tag())
+}
+
+/// Loads the content for `locale`, falling back to the compiled defaults.
+pub fn load_content(locale: Locale) -> impl Future<Output = SiteContent> + Send + 'static {
+    let path = content_path(locale);
+    #[cfg(feature = "ssr")]
+    let json = {
+        let path = path.clone();
+        async move {
+            tokio::fs::read_to_string(&path)
+                .await
+                .map_err(|err| err.to_string())
+        }
+    };
+    #[cfg(not(feature = "ssr"))]
+    let json = {
+        let url = format!("/{path}");
+        send_wrapper::SendWrapper::new(async move {
+            crate::api::request::ApiRequest::get(url)
+                .timeout(super::FILE_TIMEOUT)
+                .retries(super::FILE_RETRIES)
+                .send_text()
+                .await
+                .map_err(|err| format!("{err:?}"))
+        })
+    };
+
+    async move {
+        let content = json
+            .await
+            .and_then(|json| SiteContent::from_json(&json).map_err(|err| err.to_string()));
+        content.unwrap_or_else(|err| {
+            logging::warn!("{path}: {err}; using built-in content");
+            SiteContent::default()
+        })
+    }
+}
+
+/// Current site content, as provided by `App` before any page renders.
+pub fn use_content() -> SiteContent {
+    use_context::<SiteContent>().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Content files shipped in `public/content`, by locale.
+    const BUNDLED: &[(Locale, &str)] = &[
+        (Locale::En, include_str!("../../public/content/en.json")),
+        (Locale::Ru, include_str!("../../public/content/ru.json")),
+    ];
+
+    #[test]
+    fn compiled_defaults_are_valid() {
+        assert_eq!(SiteContent::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn bundled_files_are_valid() {
+        for (locale, json) in BUNDLED {
+            if let Err(err) = SiteContent::from_json(json) {
+                panic!("{}: {err}", content_path(*locale));
+            }
+        }
+    }
+
+    #[test]
+    fn every_locale_keeps_the_default_slugs() {
+        // Deep links (`#faq-<slug>`) must work whatever the visitor's language.
+        let slugs = |content: &SiteContent| {
+            content
+                .faq
+                .iter()
+                .map(|item| item.slug.to_string())
+                .collect::<Vec<_>>()
+        };
+        let expected = slugs(&SiteContent::default());
+        for (locale, json) in BUNDLED {
+            let content = SiteContent::from_json(json).unwrap();
+            assert_eq!(slugs(&content), expected, "{}", content_path(*locale));
+        }
+    }
+
+    fn with_faq(faq: &str) -> String {
+        format!(r#"{{ "faq": {faq}, "footer": [] }}"#)
+    }
+
+    #[test]
+    fn unknown_fields_are_rejected() {
+        let json = with_faq(r#"[{ "slug": "a", "question": "Q", "answer": "A", "emjoi": "x" }]"#);
+        assert!(matches!(
+            SiteContent::from_json(&json),
+            Err(ContentError::Decode(_))
+        ));
+    }
+
+    #[test]
+    fn bad_slugs_are_rejected() {
+        for faq in [
+            r#"[{ "slug": "Bad Slug", "question": "Q", "answer": "A" }]"#,
+            r#"[{ "slug": "a", "question": "Q", "answer": "A" },
+                { "slug": "a", "question": "Q2", "answer": "A2" }]"#,
+        ] {
+            assert!(matches!(
+                SiteContent::from_json(&with_faq(faq)),
+                Err(ContentError::Invalid(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn footer_links_must_be_https_or_mailto() {
+        let json = r#"{
+            "faq": [{ "slug": "a", "question": "Q", "answer": "A" }],
+            "footer": [{ "title": "T", "links": [{ "text": "x", "href": "javascript:alert`1`" }] }]
+        }"#;
+        assert!(matches!(
+            SiteContent::from_json(json),
+            Err(ContentError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn quote_details_are_optional_but_not_blank() {
+        let json = |quote: &str| {
+            format!(
+                r#"{{ "faq": [{{ "slug": "a", "question": "Q", "answer": "A" }}], "footer": []{quote} }}"#
+            )
+        };
+        let content = SiteContent::from_json(&json("")).unwrap();
+        assert_eq!(content.quote, QuoteDetails::default());
+        assert!(SiteContent::from_json(&json(r#", "quote": { "networkFee": "Free" }"#)).is_ok());
+        assert!(matches!(
+            SiteContent::from_json(&json(r#", "quote": { "estimatedArrival": " " }"#)),
+            Err(ContentError::Invalid(_))
+        ));
+    }
+}
@@ -1,63 +1,75 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct FooterLink {
-    pub text: &'static str,
-    pub href: &'static str,
+    pub text: Cow<'static, str>,
+    /// `https://` or `mailto:` URL.
+    pub href: Cow<'static, str>,
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct FooterSection {
-    pub title: &'static str,
-    pub links: &'static [FooterLink],
+    pub title: Cow<'static, str>,
+    pub links: Cow<'static, [FooterLink]>,
 }
 
+// Compiled-in defaults, used until (or when) `public/content/*.json` can't be loaded.
 pub const FOOTER_LINKS: &[FooterSection] = &[
     FooterSection {
-        title: "Project",
-        links: &[
+        title: Cow::Borrowed("Project"),
+        links: Cow::Borrowed(&[
             FooterLink {
-                text: "Blog",
-                href: "https://x.com/untronfi",
+                text: Cow::Borrowed("Blog"),
+                href: Cow::Borrowed("https://x.com/untronfi"),
             },
             FooterLink {
-                text: "About us",
-                href: "https://x.com/untronfi",
+                text: Cow::Borrowed("About us"),
+                href: Cow::Borrowed("https://x.com/untronfi"),
             },
             FooterLink {
-                text: "Terms of service",
-                href: "https://www.wtfpl.net/wp-content/uploads/2012/12/freedom.jpeg",
+                text: Cow::Borrowed("Terms of service"),
+                href: Cow::Borrowed(
+                    "https://www.wtfpl.net/wp-content/uploads/2012/12/freedom.jpeg",
+                ),
             },
             FooterLink {
-                text: "Brand assets",
-                href: "https://github.com/ultrasoundlabs/brandkit",
+                text: Cow::Borrowed("Brand assets"),
+                href: Cow::Borrowed("https://github.com/ultrasoundlabs/brandkit"),
             },
-        ],
+        ]),
     },
     FooterSection {
-        title: "Socials",
-        links: &[
+        title: Cow::Borrowed("Socials"),
+        links: Cow::Borrowed(&[
             FooterLink {
-                text: "X / Twitter",
-                href: "https://x.com/untronfi",
+                text: Cow::Borrowed("X / Twitter"),
+                href: Cow::Borrowed("https://x.com/untronfi"),
             },
             FooterLink {
-                text: "Telegram",
-                href: "https://t.me/untronchat",
+                text: Cow::Borrowed("Telegram"),
+                href: Cow::Borrowed("https://t.me/untronchat"),
             },
             FooterLink {
-                text: "GitHub",
-                href: "https://github.com/ultrasoundlabs",
+                text: Cow::Borrowed("GitHub"),
+                href: Cow::Borrowed("https://github.com/ultrasoundlabs"),
             },
-        ],
+        ]),
     },
     FooterSection {
-        title: "Contacts",
-        links: &[
+        title: Cow::Borrowed("Contacts"),
+        links: Cow::Borrowed(&[
             FooterLink {
-                text: "SHPS (LLC) Ultrasound Labs",
-                href: "mailto:contact@untron.finance",
+                text: Cow::Borrowed("SHPS (LLC) Ultrasound Labs"),
+                href: Cow::Borrowed("mailto:contact@untron.finance"),
             },
             FooterLink {
-                text: "contact@untron.finance",
-                href: "mailto:contact@untron.finance",
+                text: Cow::Borrowed("contact@untron.finance"),
+                href: Cow::Borrowed("mailto:contact@untron.finance"),
             },
-        ],
+        ]),
     },
 ];
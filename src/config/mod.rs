@@ -1,2 +1,13 @@
+pub mod app;
+pub mod content;
 pub mod faq_accordion;
 pub mod footer;
+
+/// Per-attempt timeout for fetching the config and content files. Pages wait
+/// for them, so a slow fetch falls back to the compiled defaults instead.
+#[cfg(not(feature = "ssr"))]
+const FILE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// Retries after a failed or timed-out fetch of those files.
+#[cfg(not(feature = "ssr"))]
+const FILE_RETRIES: u32 = 1;
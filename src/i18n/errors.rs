@@ -0,0 +1,135 @@
+use super::Locale;
+use crate::api::{ApiError, ErrorCode};
+
+/// User-facing message for a failed API request.
+pub fn api_error_message(err: &ApiError, locale: Locale) -> String {
+    if let Some(message) = err
+        .server_error()
+        .and_then(|server| server_error_message(server.code, locale))
+    {
+        return message.to_string();
+    }
+
+    let message = match (err, locale) {
+        (ApiError::Timeout, Locale::En) => "The server took too long to respond",
+        (ApiError::Timeout, Locale::Ru) => "Сервер слишком долго не отвечает",
+        (ApiError::Cancelled, Locale::En) => "Request cancelled",
+        (ApiError::Cancelled, Locale::Ru) => "Запрос отменён",
+        (ApiError::Network(_), Locale::En) => "Network error, check your connection",
+        (ApiError::Network(_), Locale::Ru) => "Ошибка сети, проверьте подключение",
+        (ApiError::Http { status, .. }, Locale::En) if *status >= 500 => {
+            "Untron is temporarily unavailable"
+        }
+        (ApiError::Http { status, .. }, Locale::Ru) if *status >= 500 => {
+            "Untron временно недоступен"
+        }
+        (
+            ApiError::Http {
+                error: Some(server),
+                ..
+            },
+            _,
+        ) if !server.message.is_empty() => {
+            // Unknown code: the backend's own (English) message beats a generic one.
+            return server.message.clone();
+        }
+        (ApiError::Http { status, .. }, Locale::En) => {
+            return format!("Request failed (HTTP {status})")
+        }
+        (ApiError::Http { status, .. }, Locale::Ru) => {
+            return format!("Запрос не выполнен (HTTP {status})")
+        }
+        (ApiError::Decode(_), Locale::En) => "Unexpected response from the server",
+        (ApiError::Decode(_), Locale::Ru) => "Неожиданный ответ сервера",
+    };
+    message.to_string()
+}
+
+fn server_error_message(code: ErrorCode, locale: Locale) -> Option<&'static str> {
+    let message = match (code, locale) {
+        (ErrorCode::AmountTooLow, Locale::En) => "Amount is below the minimum order size",
+        (ErrorCode::AmountTooLow, Locale::Ru) => "Сумма меньше минимального размера заказа",
+        (ErrorCode::AmountTooHigh, Locale::En) => "Amount is above the maximum order size",
+        (ErrorCode::AmountTooHigh, Locale::Ru) => "Сумма больше максимального размера заказа",
+        (ErrorCode::InsufficientLiquidity, Locale::En) => {
+            "Not enough liquidity for this amount right now, try a smaller one"
+        }
+        (ErrorCode::InsufficientLiquidity, Locale::Ru) => {
+            "Сейчас недостаточно ликвидности для этой суммы, попробуйте меньше"
+        }
+        (ErrorCode::InvalidBeneficiary, Locale::En) => "This recipient address isn't valid",
+        (ErrorCode::InvalidBeneficiary, Locale::Ru) => "Неверный адрес получателя",
+        (ErrorCode::UnsupportedChain, Locale::En) => "This chain isn't supported yet",
+        (ErrorCode::UnsupportedChain, Locale::Ru) => "Эта сеть пока не поддерживается",
+        (ErrorCode::RateChanged, Locale::En) => "The rate has changed, please try again",
+        (ErrorCode::RateChanged, Locale::Ru) => "Курс изменился, попробуйте ещё раз",
+        (ErrorCode::RateLimited, Locale::En) => "Too many requests, wait a moment and retry",
+        (ErrorCode::RateLimited, Locale::Ru) => "Слишком много запросов, подождите немного",
+        (ErrorCode::Unknown, _) => return None,
+    };
+    Some(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field_error(code: &str, field: &str) -> ApiError {
+        let body = format!(
+            r#"{{"error": {{"code": "{code}", "message": "backend text", "field": "{field}"}}}}"#
+        );
+        ApiError::http(400, body)
+    }
+
+    #[test]
+    fn field_errors_are_translated() {
+        let err = field_error("AMOUNT_TOO_LOW", "fromAmount");
+        assert_eq!(
+            api_error_message(&err, Locale::En),
+            "Amount is below the minimum order size"
+        );
+        assert_eq!(
+            api_error_message(&err, Locale::Ru),
+            "Сумма меньше минимального размера заказа"
+        );
+
+        let err = field_error("INVALID_BENEFICIARY", "beneficiary");
+        assert_eq!(
+            api_error_message(&err, Locale::En),
+            "This recipient address isn't valid"
+        );
+        assert_eq!(
+            api_error_message(&err, Locale::Ru),
+            "Неверный адрес получателя"
+        );
+    }
+
+    #[test]
+    fn unknown_codes_show_the_backend_message() {
+        let err = field_error("SOMETHING_NEW", "rate");
+        assert_eq!(api_error_message(&err, Locale::En), "backend text");
+        assert_eq!(api_error_message(&err, Locale::Ru), "backend text");
+    }
+
+    #[test]
+    fn other_failures_get_generic_messages() {
+        let err = ApiError::http(404, "Not Found".into());
+        assert_eq!(
+            api_error_message(&err, Locale::En),
+            "Request failed (HTTP 404)"
+        );
+        assert_eq!(
+            api_error_message(&err, Locale::Ru),
+            "Запрос не выполнен (HTTP 404)"
+        );
+        let err = ApiError::http(503, String::new());
+        assert_eq!(
+            api_error_message(&err, Locale::En),
+            "Untron is temporarily unavailable"
+        );
+        assert_eq!(
+            api_error_message(&ApiError::Timeout, Locale::Ru),
+            "Сервер слишком долго не отвечает"
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use leptos::prelude::*;
+
+mod errors;
+
+pub use errors::api_error_message;
+
+/// Language of user-facing text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    En,
+    Ru,
+}
+
+impl Locale {
+    /// Picks the locale matching a BCP 47 tag such as `ru-RU`, defaulting to English.
+    pub fn from_tag(tag: &str) -> Self {
+        match tag.split(['-', '_']).next().unwrap_or_default() {
+            "ru" => Self::Ru,
+            _ => Self::En,
+        }
+    }
+
+    /// Primary language subtag, e.g. `ru`.
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::Ru => "ru",
+        }
+    }
+
+    /// Picks the locale for the current page: from `Accept-Language` on the
+    /// server, from the `lang` the server rendered when hydrating (so both
+    /// sides agree), and from the browser's preferred language otherwise.
+    pub fn detect() -> Self {
+        #[cfg(feature = "ssr")]
+        let tag = use_context::<axum::http::request::Parts>().and_then(|parts| {
+            let header = parts.headers.get(axum::http::header::ACCEPT_LANGUAGE)?;
+            Some(header.to_str().ok()?.split(',').next()?.trim().to_string())
+        });
+        #[cfg(feature = "hydrate")]
+        let tag = document()
+            .document_element()
+            .and_then(|html| html.get_attribute("lang"));
+        #[cfg(not(any(feature = "ssr", feature = "hydrate")))]
+        let tag = web_sys::window().and_then(|w| w.navigator().language());
+
+        tag.map(|tag| Self::from_tag(&tag.to_ascii_lowercase()))
+            .unwrap_or_default()
+    }
+}
+
+/// Current locale, as provided by `App`.
+pub fn use_locale() -> Locale {
+    use_context::<Locale>().unwrap_or_default()
+}
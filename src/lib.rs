@@ -1,35 +1,212 @@
+#![recursion_limit = "256"]
+
 use leptos::prelude::*;
 use leptos_meta::*;
-use leptos_router::{components::*, path};
+use leptos_router::{components::*, path, SsrMode};
 
 // Modules
+mod address_book;
+mod api;
+#[cfg(all(test, target_arch = "wasm32", feature = "mock-api"))]
+mod browser_tests;
 mod components;
 mod config;
+mod i18n;
 mod pages;
+#[cfg(feature = "ssr")]
+pub mod server;
 mod utils;
+mod wallet;
+#[cfg(feature = "widget")]
+mod widget;
 
 // Top-Level pages
+use crate::components::header::Header;
+use crate::config::app::{load_app_config, AppConfig};
+use crate::config::content::{load_content, SiteContent};
+use crate::pages::address_book::AddressBookPage;
+use crate::pages::batch::BatchPage;
 use crate::pages::home::Home;
+use crate::pages::order::OrderPage;
+use crate::pages::pay::PayPage;
+
+/// Deployment config and site copy, loaded once per page load (or, on the
+/// server, per request) and serialized into the page for hydration.
+#[derive(Clone, Copy)]
+struct AppData {
+    config: Resource<AppConfig>,
+    content: Resource<SiteContent>,
+}
 
 #[component]
 pub fn App() -> impl IntoView {
     // Provides context that manages stylesheets, titles, meta tags, etc.
     provide_meta_context();
+    let locale = i18n::Locale::detect();
+    provide_context(locale);
+    provide_context(address_book::AddressBook::new());
+    provide_context(wallet::TronWallet::new());
+
+    provide_context(AppData {
+        config: Resource::new(|| (), |_| load_app_config()),
+        content: Resource::new(|| (), move |_| load_content(locale)),
+    });
 
     view! {
-        <Html attr:lang="en" attr:dir="ltr" attr:data-theme="light" />
+        <Html attr:lang=locale.tag() attr:dir="ltr" attr:data-theme="light" />
 
-        // sets the document title
+        // sets the document title; charset and viewport are part of the
+        // document itself (`index.html`, `shell`)
         <Title text="Untron" />
 
-        // injects metadata in the <head> of the page
-        <Meta charset="UTF-8" />
-        <Meta name="viewport" content="width=device-width, initial-scale=1.0" />
-
         <Router>
             <Routes fallback=|| view! { NotFound }>
-                <Route path=path!("/") view=Home />
+                // Rendered in full before sending, so crawlers (and pre-rendered
+                // files) get the FAQ and footer as plain HTML.
+                <Route
+                    path=path!("/")
+                    ssr=SsrMode::Async
+                    view=|| view! { <WithAppData with_info=true><Home /></WithAppData> }
+                />
+                // Likewise, so `noindex` is in the `<head>` crawlers first see.
+                <Route
+                    path=path!("/order/:id")
+                    ssr=SsrMode::Async
+                    view=|| view! { <WithAppData><OrderPage /></WithAppData> }
+                />
+                <Route
+                    path=path!("/pay")
+                    ssr=SsrMode::Async
+                    view=|| view! { <WithAppData with_info=true><PayPage /></WithAppData> }
+                />
+                <Route
+                    path=path!("/batch")
+                    ssr=SsrMode::Async
+                    view=|| view! { <WithAppData><BatchPage /></WithAppData> }
+                />
+                <Route
+                    path=path!("/address-book")
+                    ssr=SsrMode::Async
+                    view=|| view! { <WithAppData><AddressBookPage /></WithAppData> }
+                />
             </Routes>
         </Router>
     }
 }
+
+/// Renders `children` once the config and content are known, so every API
+/// call goes to the configured backend and the server sends the real copy;
+/// until then the page shows [`LoadingPage`]. With `with_info`, also waits
+/// for [`api::info::prefetch_info`].
+#[component]
+fn WithAppData(children: ChildrenFn, #[prop(optional)] with_info: bool) -> impl IntoView {
+    let AppData { config, content } = expect_context::<AppData>();
+    let children = StoredValue::new(children);
+    // Liquidity changes by the minute; a pre-rendered page mustn't freeze it.
+    #[cfg(feature = "ssr")]
+    let is_prerendering = use_context::<server::Prerendering>().is_some();
+    #[cfg(not(feature = "ssr"))]
+    let is_prerendering = false;
+    let info = with_info.then(|| {
+        Resource::new(
+            || (),
+            move |_| async move {
+                let config = config.await;
+                let enabled = config.features.prefetch_info && !is_prerendering;
+                api::info::prefetch_info(config.api_base_url, enabled).await
+            },
+        )
+    });
+
+    view! {
+        <Suspense fallback=LoadingPage>
+            {move || Suspend::new(async move {
+                provide_context(config.await);
+                provide_context(content.await);
+                if let Some(info) = info {
+                    provide_context(api::info::PrefetchedInfo(info.await));
+                }
+                // Reads while building the page mustn't re-run this block.
+                untrack(|| children.read_value()())
+            })}
+        </Suspense>
+    }
+}
+
+/// Shown while a page waits for its config and content.
+#[component]
+fn LoadingPage() -> impl IntoView {
+    view! {
+        <div class="min-h-screen bg-background flex flex-col">
+            <Header />
+            <main class="flex-1 w-full mx-auto px-4 py-8 flex flex-col items-center">
+                <p class="text-muted-foreground" role="status">"Loading…"</p>
+            </main>
+        </div>
+    }
+}
+
+/// Charset and viewport, shared by the server-rendered documents. `index.html`
+/// carries the same tags for the client-only build.
+#[cfg(feature = "ssr")]
+#[component]
+fn DocumentMeta() -> impl IntoView {
+    view! {
+        <meta charset="utf-8" />
+        <meta name="viewport" content="width=device-width, initial-scale=1" />
+    }
+}
+
+/// HTML document the SSR server renders `App` into.
+#[cfg(feature = "ssr")]
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    view! {
+        <!DOCTYPE html>
+        <html>
+            <head>
+                <DocumentMeta />
+                <AutoReload options=options.clone() />
+                <HydrationScripts options />
+                <link rel="stylesheet" href="/pkg/untron-frontend.css" />
+                <MetaTags />
+            </head>
+            <body>
+                <App />
+            </body>
+        </html>
+    }
+}
+
+/// Document for routes that can't be pre-rendered (`/order/:id`, `/pay`): the
+/// same head as [`shell`], with `App` rendered in the browser.
+#[cfg(feature = "ssr")]
+pub fn client_shell(options: LeptosOptions) -> impl IntoView {
+    view! {
+        <!DOCTYPE html>
+        <html>
+            <head>
+                <DocumentMeta />
+                <HydrationScripts options />
+                <link rel="stylesheet" href="/pkg/untron-frontend.css" />
+            </head>
+            // Tells `hydrate` there's nothing to hydrate.
+            <body data-client-render=""></body>
+        </html>
+    }
+}
+
+/// Entry point of the `hydrate` build: attaches to the server-rendered page,
+/// or renders into a [`client_shell`].
+#[cfg(feature = "hydrate")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn hydrate() {
+    console_error_panic_hook::set_once();
+    let client_render = leptos::prelude::document()
+        .body()
+        .is_some_and(|body| body.has_attribute("data-client-render"));
+    if client_render {
+        leptos::mount::mount_to_body(App);
+    } else {
+        leptos::mount::hydrate_body(App);
+    }
+}
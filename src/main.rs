@@ -1,6 +1,28 @@
-use untron_frontend::App;
+#![recursion_limit = "256"]
 
+#[cfg(feature = "ssr")]
+#[tokio::main]
+async fn main() {
+    use leptos::prelude::*;
+    use untron_frontend::server::router;
+
+    let conf = get_configuration(None).expect("valid [package.metadata.leptos]");
+    let addr = conf.leptos_options.site_addr;
+    let app = router(conf.leptos_options, false);
+
+    let listener = tokio::net::TcpListener::bind(&addr)
+        .await
+        .expect("site address is free");
+    leptos::logging::log!("listening on http://{addr}");
+    axum::serve(listener, app.into_make_service())
+        .await
+        .expect("server runs");
+}
+
+#[cfg(not(feature = "ssr"))]
 fn main() {
+    use untron_frontend::App;
+
     console_error_panic_hook::set_once();
     leptos::mount::mount_to_body(App)
 }
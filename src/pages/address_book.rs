@@ -0,0 +1,218 @@
+use leptos::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+// Local imports
+use crate::address_book::{use_address_book, AddressBook, SavedRecipient};
+use crate::components::{footer::Footer, header::Header, page_meta::PageMeta};
+use crate::config::app::use_app_config;
+use crate::config::content::use_content;
+use crate::utils::address::is_valid_evm_address;
+use crate::utils::files::{download, read_text};
+use crate::utils::time::format_unix;
+
+/// Name of the exported address book.
+const EXPORT_FILE: &str = "untron-address-book.json";
+
+/// Lists the saved recipients for editing, and imports or exports them.
+#[component]
+pub fn AddressBookPage() -> impl IntoView {
+    let book = use_address_book().expect("AddressBook is provided by App");
+    let content = use_content();
+    // Result of the last add or import, and whether it failed.
+    let message = RwSignal::new(None::<(String, bool)>);
+
+    // ---------------- Adding ---------------------------
+    let new_label = RwSignal::new(String::new());
+    let new_address = RwSignal::new(String::new());
+    let add = move || {
+        let recipient = SavedRecipient::new(&new_label.get(), &new_address.get());
+        if recipient.label.is_empty() || !is_valid_evm_address(&recipient.address) {
+            message.set(Some(("Enter a label and a valid address.".into(), true)));
+            return;
+        }
+        book.save(recipient);
+        new_label.set(String::new());
+        new_address.set(String::new());
+        message.set(None);
+    };
+
+    // ---------------- Import / export ------------------
+    let on_import =
+        move |ev: leptos::ev::Targeted<leptos::ev::Event, web_sys::HtmlInputElement>| {
+            let Some(file) = ev.target().files().and_then(|files| files.get(0)) else {
+                return;
+            };
+            spawn_local(async move {
+                let result = match read_text(&file).await {
+                    Some(json) => book.import_json(&json).map_err(|err| err.to_string()),
+                    None => Err("Couldn't read the file.".into()),
+                };
+                message.set(Some(match result {
+                    Ok(count) => (format!("Imported {count} recipients."), false),
+                    Err(err) => (err, true),
+                }));
+            });
+        };
+    let export = move |_| {
+        if let Err(err) = download(EXPORT_FILE, "application/json", &book.export_json()) {
+            leptos::logging::error!("couldn't export the address book: {err:?}");
+        }
+    };
+
+    view! {
+        // Nothing here is public; the page only shows this browser's data.
+        <PageMeta
+            title="Address book · Untron"
+            description="Recipients saved in this browser."
+            path="/address-book"
+            noindex=true
+        />
+
+        <div class="min-h-screen bg-background flex flex-col">
+            <Header />
+
+            <main class="flex-1 w-full mx-auto px-4 py-8 flex flex-col items-center">
+                <div class="w-full max-w-[800px] bg-card rounded-[22px] p-6 space-y-4">
+                    <h1 class="text-2xl font-medium">"Address book"</h1>
+                    <p class="text-muted-foreground">
+                        "Recipients are saved in this browser only. Export them to move them elsewhere."
+                    </p>
+
+                    <Show
+                        when=move || book.entries().with(|entries| !entries.is_empty())
+                        fallback=|| {
+                            view! { <p class="text-muted-foreground">"No saved recipients yet."</p> }
+                        }
+                    >
+                        <ul class="space-y-3">
+                            <For
+                                each=move || book.entries().get()
+                                key=|recipient| recipient.address.to_lowercase()
+                                let:recipient
+                            >
+                                <RecipientRow book recipient />
+                            </For>
+                        </ul>
+                    </Show>
+
+                    // ---------------- Add -----------------
+                    <form
+                        class="flex flex-wrap gap-2"
+                        on:submit=move |ev| {
+                            ev.prevent_default();
+                            add();
+                        }
+                    >
+                        <input
+                            type="text"
+                            class="bg-white rounded-full px-4 py-1.5 outline-none"
+                            placeholder="Label"
+                            aria-label="Label"
+                            prop:value=new_label
+                            on:input:target=move |ev| new_label.set(ev.target().value())
+                        />
+                        <input
+                            type="text"
+                            class="flex-1 bg-white rounded-full px-4 py-1.5 outline-none font-mono"
+                            placeholder="0x…"
+                            aria-label="Address"
+                            prop:value=new_address
+                            on:input:target=move |ev| new_address.set(ev.target().value())
+                        />
+                        <button
+                            type="submit"
+                            class="bg-black text-white text-base font-medium px-4 py-1.5 rounded-full"
+                        >
+                            "Add"
+                        </button>
+                    </form>
+
+                    // ------------- Import / export ------------
+                    <div class="flex flex-wrap items-center gap-4">
+                        <label class="text-base">
+                            "Import "
+                            <input type="file" accept=".json,application/json" on:change:target=on_import />
+                        </label>
+                        <button
+                            type="button"
+                            class="bg-black text-white text-base font-medium px-4 py-1.5 rounded-full"
+                            on:click=export
+                        >
+                            "Export"
+                        </button>
+                    </div>
+                    {move || {
+                        message
+                            .get()
+                            .map(|(text, is_error)| {
+                                let class = if is_error { "text-red-500" } else { "text-muted-foreground" };
+                                view! { <p class=class>{text}</p> }
+                            })
+                    }}
+                </div>
+            </main>
+
+            <Footer footer_links=content.footer />
+        </div>
+    }
+}
+
+/// One saved recipient: its label and chain can be changed in place.
+#[component]
+fn RecipientRow(book: AddressBook, recipient: SavedRecipient) -> impl IntoView {
+    let chains = use_app_config().chains;
+    let address = StoredValue::new(recipient.address.clone());
+    let last_used = recipient.last_used.map_or_else(
+        || "Never used".to_string(),
+        |at| format!("Last used {}", format_unix(at)),
+    );
+    let chain = recipient.chain;
+
+    view! {
+        <li class="bg-background rounded-[12px] p-3 space-y-2">
+            <div class="flex flex-wrap items-center gap-2">
+                <input
+                    type="text"
+                    class="bg-white rounded-full px-4 py-1.5 outline-none font-medium"
+                    aria-label="Label"
+                    prop:value=recipient.label
+                    on:change:target=move |ev| {
+                        let label = ev.target().value().trim().to_string();
+                        book.edit(&address.read_value(), |entry| entry.label = label);
+                    }
+                />
+                <select
+                    class="bg-white rounded-full px-3 py-1.5"
+                    aria-label="Preferred chain"
+                    on:change:target=move |ev| {
+                        let chain = ev.target().value().parse().ok();
+                        book.edit(&address.read_value(), |entry| entry.chain = chain);
+                    }
+                >
+                    <option value="" selected=chain.is_none()>
+                        "Default chain"
+                    </option>
+                    {chains
+                        .into_iter()
+                        .map(|c| {
+                            view! {
+                                <option value=c.id.to_string() selected=chain == Some(c.id)>
+                                    {c.name}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+                <button
+                    type="button"
+                    class="ml-auto text-red-500"
+                    on:click=move |_| book.remove(&address.read_value())
+                >
+                    "Delete"
+                </button>
+            </div>
+            <p class="font-mono text-sm break-all">{recipient.address}</p>
+            <p class="text-sm text-muted-foreground">{last_used}</p>
+        </li>
+    }
+}
@@ -0,0 +1,381 @@
+use leptos::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+// Local imports
+use crate::api::idempotency;
+use crate::api::info::{fetch_info, InfoState};
+use crate::api::order::{create_order, CreateOrderRequest};
+use crate::api::{ApiError, Cancellation};
+use crate::components::{footer::Footer, header::Header, page_meta::PageMeta};
+use crate::config::app::use_app_config;
+use crate::config::content::use_content;
+use crate::i18n::{api_error_message, use_locale};
+use crate::utils::files::{download, read_text};
+use crate::utils::payout_csv::{parse_payouts, results_csv, PayoutRow, PayoutStatus, RowError};
+use crate::utils::units::*;
+
+/// Name of the exported results file.
+const RESULTS_FILE: &str = "untron-payouts.csv";
+
+/// Pays many recipients at once: one order per row of an uploaded CSV,
+/// created one after another.
+#[component]
+pub fn BatchPage() -> impl IntoView {
+    // ---------------------- STATE ----------------------
+    let app_config = use_app_config();
+    let chain_ids = StoredValue::new(app_config.chains.iter().map(|c| c.id).collect::<Vec<_>>());
+    let chain_name = {
+        let chains = app_config.chains.clone();
+        move |id: u32| {
+            chains
+                .iter()
+                .find(|c| c.id == id)
+                .map_or_else(|| id.to_string(), |c| c.name.clone())
+        }
+    };
+    let features = app_config.features;
+    let api_base_url = StoredValue::new(app_config.api_base_url);
+    let locale = use_locale();
+    let content = use_content();
+
+    let csv_text = RwSignal::new(String::new());
+    let row_errors = RwSignal::new(Vec::<RowError>::new());
+    // Valid rows and what happened to their order.
+    let payouts = RwSignal::new(Vec::<(PayoutRow, PayoutStatus)>::new());
+    let is_running = RwSignal::new(false);
+    // Once an order exists the rows are locked, so their statuses stay true.
+    let has_orders = Signal::derive(move || {
+        payouts.with(|p| p.iter().any(|(_, s)| matches!(s, PayoutStatus::Created(_))))
+    });
+    // Rows are retried under an idempotency key of their own (this batch's id
+    // plus the line), so an order whose outcome is unknown is never repeated.
+    let batch_id = StoredValue::new(None::<String>);
+    let load_csv = move |text: String| {
+        if has_orders.get_untracked() {
+            return;
+        }
+        let (rows, errors) = parse_payouts(&text, &chain_ids.read_value())
+            .into_iter()
+            .partition::<Vec<_>, _>(Result::is_ok);
+        payouts.set(
+            rows.into_iter()
+                .flatten()
+                .map(|row| (row, PayoutStatus::Pending))
+                .collect(),
+        );
+        row_errors.set(errors.into_iter().filter_map(Result::err).collect());
+        csv_text.set(text);
+    };
+
+    // Aborts in-flight requests when the page unmounts.
+    let cancellation = Cancellation::on_cleanup();
+
+    let info = {
+        let cancellation = cancellation.clone();
+        LocalResource::new(move || fetch_info(api_base_url.get_value(), cancellation.clone()))
+    };
+    let info_state = Signal::derive(move || {
+        // `info` never loads on the server (see `UntronWidget`).
+        InfoState::from_resource(if cfg!(feature = "ssr") {
+            None
+        } else {
+            info.get()
+        })
+    });
+
+    // Rows still to pay: pending ones, and failed ones being retried.
+    let unpaid_units = Signal::derive(move || {
+        payouts.with(|payouts| {
+            payouts
+                .iter()
+                .filter(|(_, status)| !matches!(status, PayoutStatus::Created(_)))
+                .map(|(row, _)| row.receive_units)
+                .sum::<u64>()
+        })
+    });
+    let below_min = Signal::derive(move || {
+        let min = info_state.get().min_units().unwrap_or(0);
+        payouts.with(|payouts| {
+            payouts
+                .iter()
+                .filter(|(row, _)| row.receive_units < min)
+                .map(|(row, _)| row.line)
+                .collect::<Vec<_>>()
+        })
+    });
+    let exceeds_liquidity = Signal::derive(move || {
+        info_state
+            .get()
+            .max_units()
+            .is_some_and(|max| unpaid_units.get() > max)
+    });
+    let progress = Signal::derive(move || {
+        payouts.with(|payouts| {
+            let done = payouts
+                .iter()
+                .filter(|(_, status)| *status != PayoutStatus::Pending)
+                .count();
+            (done, payouts.len())
+        })
+    });
+
+    let can_create = move || {
+        features.orders
+            && !is_running.get()
+            && row_errors.with(Vec::is_empty)
+            && unpaid_units.get() > 0
+            && info_state.get().is_ready()
+            && below_min.with(Vec::is_empty)
+            && !exceeds_liquidity.get()
+    };
+
+    // ---------------- Order creation -------------------
+    let create_orders = move |_| {
+        if !can_create() {
+            return;
+        }
+        is_running.set(true);
+        let batch_id = batch_id
+            .try_update_value(|id| id.get_or_insert_with(idempotency::new_key).clone())
+            .unwrap_or_default();
+        // Failed rows are retried from scratch.
+        payouts.update(|payouts| {
+            for (_, status) in payouts.iter_mut() {
+                if matches!(status, PayoutStatus::Failed(_)) {
+                    *status = PayoutStatus::Pending;
+                }
+            }
+        });
+        let cancellation = cancellation.clone();
+        spawn_local(async move {
+            let pending = payouts.with_untracked(|payouts| {
+                payouts
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (_, status))| *status == PayoutStatus::Pending)
+                    .map(|(i, (row, _))| (i, row.clone()))
+                    .collect::<Vec<_>>()
+            });
+            for (i, row) in pending {
+                let slot = format!("batch:{batch_id}:{}", row.line);
+                let order = CreateOrderRequest {
+                    to_coin: "usdt",
+                    to_chain: row.chain,
+                    from_amount: convert_receive_to_send(row.receive_units, SWAP_RATE_UNITS),
+                    rate: SWAP_RATE_UNITS,
+                    beneficiary: row.recipient,
+                };
+                let status =
+                    match create_order(&api_base_url.get_value(), &order, &slot, &cancellation)
+                        .await
+                    {
+                        Ok(json) => PayoutStatus::Created(json.id),
+                        // The page is gone; stop without touching its state.
+                        Err(ApiError::Cancelled) => return,
+                        Err(err) => PayoutStatus::Failed(api_error_message(&err, locale)),
+                    };
+                payouts.update(|payouts| payouts[i].1 = status);
+            }
+            is_running.set(false);
+            // The orders used up liquidity.
+            info.refetch();
+        });
+    };
+
+    // ---------------- File import / export -------------
+    let on_file = move |ev: leptos::ev::Targeted<leptos::ev::Event, web_sys::HtmlInputElement>| {
+        let Some(file) = ev.target().files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        spawn_local(async move {
+            if let Some(text) = read_text(&file).await {
+                load_csv(text);
+            }
+        });
+    };
+    let export_results = move |_| {
+        let csv = payouts.with_untracked(|payouts| results_csv(payouts));
+        if let Err(err) = download(RESULTS_FILE, "text/csv", &csv) {
+            leptos::logging::error!("couldn't export results: {err:?}");
+        }
+    };
+
+    // ---------------- Derived UI fragments -------------
+    let status_view = move |status: PayoutStatus| match status {
+        PayoutStatus::Pending => {
+            view! { <span class="text-muted-foreground">"Pending"</span> }.into_any()
+        }
+        PayoutStatus::Created(id) => {
+            let href = format!("/order/{}", String::from(js_sys::encode_uri_component(&id)));
+            view! {
+                <a href=href target="_blank" class="font-mono underline break-all">
+                    {id}
+                </a>
+            }
+            .into_any()
+        }
+        PayoutStatus::Failed(err) => view! { <span class="text-red-500">{err}</span> }.into_any(),
+    };
+
+    let summary = move || {
+        let (done, total) = progress.get();
+        let total_units = payouts.with(|p| p.iter().map(|(row, _)| row.receive_units).sum());
+        let mut lines = vec![format!(
+            "{total} payouts, {} USDT in total",
+            units_to_string(total_units),
+        )];
+        if is_running.get() {
+            lines.push(format!("Creating orders… {done} / {total}"));
+        }
+        match info_state.get() {
+            _ if !features.orders => {
+                lines.push("Swaps are paused for maintenance. Please check back soon.".into())
+            }
+            InfoState::Loading => lines.push("Checking available liquidity…".into()),
+            InfoState::Unavailable(err) => lines.push(format!(
+                "Couldn't load available liquidity ({}). Retrying shortly…",
+                api_error_message(&err, locale),
+            )),
+            InfoState::Ready(info) => {
+                if exceeds_liquidity.get() {
+                    lines.push(format!(
+                        "The unpaid total of {} USDT is above the available liquidity of {} USDT.",
+                        units_to_string(unpaid_units.get()),
+                        units_to_string(info.available_liquidity),
+                    ));
+                }
+                let below_min = below_min.get();
+                if !below_min.is_empty() {
+                    lines.push(format!(
+                        "Minimum output is {} USDT (lines {}).",
+                        units_to_string(info.min_order_output),
+                        below_min
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ));
+                }
+            }
+        }
+        lines
+            .into_iter()
+            .map(|line| view! { <p class="text-muted-foreground">{line}</p> })
+            .collect_view()
+    };
+
+    view! {
+        <PageMeta
+            title="Batch payouts · Untron"
+            description="Pay many recipients from Tron USDT at once by uploading a CSV file."
+            path="/batch"
+        />
+
+        <div class="min-h-screen bg-background flex flex-col">
+            <Header />
+
+            <main class="flex-1 w-full mx-auto px-4 py-8 flex flex-col items-center">
+                <div class="w-full max-w-[800px] space-y-4">
+                    <div class="bg-card rounded-[22px] p-6 space-y-4">
+                        <h1 class="text-2xl font-medium">"Batch payouts"</h1>
+                        <p class="text-muted-foreground">
+                            "One line per recipient: " <code>"address,amount,chain"</code>
+                            ". The amount is what the recipient receives; the chain id is optional."
+                        </p>
+                        <input
+                            type="file"
+                            accept=".csv,text/csv"
+                            on:change:target=on_file
+                            disabled=move || is_running.get() || has_orders.get()
+                        />
+                        <textarea
+                            rows="8"
+                            class="w-full rounded-[12px] p-3 font-mono text-sm bg-background"
+                            placeholder="0x…,25.5,42161"
+                            prop:value=csv_text
+                            on:input:target=move |ev| load_csv(ev.target().value())
+                            disabled=move || is_running.get() || has_orders.get()
+                        ></textarea>
+                        <Show when=move || has_orders.get()>
+                            <p class="text-sm text-muted-foreground">
+                                "Orders were created from this list, so it can no longer be edited. Export the results, then reload the page to start a new batch."
+                            </p>
+                        </Show>
+                        <ul class="text-sm text-red-500">
+                            <For each=move || row_errors.get() key=|err| err.line let:err>
+                                <li>{err.to_string()}</li>
+                            </For>
+                        </ul>
+                    </div>
+
+                    <Show when=move || payouts.with(|p| !p.is_empty())>
+                        <div class="bg-card rounded-[22px] p-6 space-y-4">
+                            <table class="w-full text-sm text-left">
+                                <thead>
+                                    <tr>
+                                        <th>"Line"</th>
+                                        <th>"Recipient"</th>
+                                        <th>"Amount"</th>
+                                        <th>"Chain"</th>
+                                        <th>"Order"</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {
+                                        let chain_name = chain_name.clone();
+                                        move || {
+                                            payouts
+                                                .get()
+                                                .into_iter()
+                                                .map(|(row, status)| {
+                                                    view! {
+                                                        <tr>
+                                                            <td>{row.line}</td>
+                                                            <td class="font-mono break-all">{row.recipient}</td>
+                                                            <td>{units_to_string(row.receive_units)}</td>
+                                                            <td>{chain_name(row.chain)}</td>
+                                                            <td>{status_view(status)}</td>
+                                                        </tr>
+                                                    }
+                                                })
+                                                .collect_view()
+                                        }
+                                    }
+                                </tbody>
+                            </table>
+                            {summary}
+                            <div class="flex gap-4">
+                                <button
+                                    class="flex-1 py-4 rounded-[22px] text-[20px] font-medium bg-black text-white"
+                                    on:click=create_orders.clone()
+                                    disabled=move || !can_create()
+                                >
+                                    {move || {
+                                        if is_running.get() {
+                                            "Processing…"
+                                        } else if payouts.with(|p| {
+                                            p.iter().any(|(_, s)| matches!(s, PayoutStatus::Failed(_)))
+                                        }) {
+                                            "Retry failed"
+                                        } else {
+                                            "Create orders"
+                                        }
+                                    }}
+                                </button>
+                                <button
+                                    class="py-4 px-6 rounded-[22px] text-[20px] font-medium bg-background"
+                                    on:click=export_results
+                                    disabled=move || is_running.get() || progress.get().0 == 0
+                                >
+                                    "Export results"
+                                </button>
+                            </div>
+                        </div>
+                    </Show>
+                </div>
+            </main>
+
+            <Footer footer_links=content.footer />
+        </div>
+    }
+}
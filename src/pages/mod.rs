@@ -1 +1,5 @@
+pub mod address_book;
+pub mod batch;
 pub mod home;
+pub mod order;
+pub mod pay;
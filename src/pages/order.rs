@@ -0,0 +1,39 @@
+use leptos::prelude::*;
+use leptos_router::hooks::{use_location, use_params_map};
+
+// Local imports
+use crate::components::{
+    footer::Footer, header::Header, order::tracker::OrderTracker, page_meta::PageMeta,
+};
+use crate::config::content::use_content;
+
+#[component]
+pub fn OrderPage() -> impl IntoView {
+    let params = use_params_map();
+    let order_id = move || params.read().get("id").unwrap_or_default();
+    let content = use_content();
+
+    view! {
+        // Order links are private; keep them out of search results.
+        <PageMeta
+            title="Your order · Untron"
+            description="Status of an Untron transfer."
+            path=use_location().pathname.get_untracked()
+            noindex=true
+        />
+
+        <div class="min-h-screen bg-background flex flex-col">
+            <Header />
+
+            <main class="flex-1 w-full mx-auto px-4 py-8 flex flex-col items-center">
+                <div class="w-full max-w-[560px] bg-card rounded-[22px] p-6 space-y-4">
+                    <h1 class="text-2xl font-medium">"Your order"</h1>
+                    <p class="font-mono text-sm break-all text-muted-foreground">{order_id}</p>
+                    <OrderTracker order_id=Signal::derive(order_id) />
+                </div>
+            </main>
+
+            <Footer footer_links=content.footer />
+        </div>
+    }
+}
@@ -0,0 +1,149 @@
+use leptos::prelude::*;
+use leptos_router::hooks::{use_navigate, use_query_map};
+use std::time::Duration;
+
+// Local imports
+use crate::components::{
+    address_chunks::AddressChunks, footer::Footer, header::Header, page_meta::PageMeta,
+    untron_widget::UntronWidget,
+};
+use crate::config::app::use_app_config;
+use crate::config::content::use_content;
+use crate::utils::payment_request::{PaymentRequest, PaymentRequestError};
+use crate::utils::swap_link::SwapLink;
+use crate::utils::time::{format_unix, now_secs};
+use crate::utils::units::units_to_string;
+
+/// Pays a merchant's payment request (`/pay?to=…&amount=…`): the recipient
+/// and the amount they receive are fixed, the customer only sends.
+#[component]
+pub fn PayPage() -> impl IntoView {
+    let app_config = use_app_config();
+    let query = use_query_map();
+    let chain_ids = app_config.chains.iter().map(|c| c.id).collect::<Vec<_>>();
+    let request = PaymentRequest::from_query(
+        |key| query.with_untracked(|q| q.get(key)),
+        &chain_ids,
+        now_secs(),
+    );
+    let content = use_content();
+
+    let navigate = use_navigate();
+    let open_order = move |id: String| {
+        let id = String::from(js_sys::encode_uri_component(&id));
+        navigate(&format!("/order/{id}"), Default::default());
+    };
+
+    let body = match request {
+        Ok(request) => {
+            let chain_name = app_config
+                .chains
+                .iter()
+                .find(|c| c.id == request.chain)
+                .map(|c| c.name.clone())
+                .unwrap_or_default();
+            view! { <PaymentForm request chain_name open_order /> }.into_any()
+        }
+        Err(err) => view! { <InvalidRequest err /> }.into_any(),
+    };
+
+    view! {
+        // Requests are per-customer; keep them out of search results.
+        <PageMeta
+            title="Payment request · Untron"
+            description="Pay a USDT payment request from any Tron wallet."
+            path="/pay"
+            noindex=true
+        />
+
+        <div class="min-h-screen bg-background flex flex-col">
+            <Header />
+
+            <main class="flex-1 w-full mx-auto px-4 py-8 flex flex-col items-center">
+                <div class="w-full max-w-[560px] space-y-4">{body}</div>
+            </main>
+
+            <Footer footer_links=content.footer />
+        </div>
+    }
+}
+
+#[component]
+fn PaymentForm(
+    request: PaymentRequest,
+    chain_name: String,
+    #[prop(into)] open_order: Callback<String>,
+) -> impl IntoView {
+    // The page stays open while the customer pays, so the request can expire
+    // under them.
+    let expired = RwSignal::new(false);
+    if let Some(expires_at) = request.expires_at {
+        Effect::new(move |_| {
+            let remaining = Duration::from_secs(expires_at.saturating_sub(now_secs()));
+            // Longer delays overflow the browser's timer and fire at once.
+            if remaining.as_millis() > i32::MAX as u128 {
+                return;
+            }
+            if let Ok(handle) = set_timeout_with_handle(move || expired.set(true), remaining) {
+                on_cleanup(move || handle.clear());
+            }
+        });
+    }
+
+    let amount = units_to_string(request.receive_units);
+    let expiry = request.expires_at.map(|at| {
+        view! {
+            <p class="text-sm text-muted-foreground">"Expires " {format_unix(at)}</p>
+        }
+    });
+    let initial = SwapLink {
+        amount: None,
+        to: Some(request.recipient.clone()),
+        chain: Some(request.chain),
+    };
+
+    view! {
+        <div class="bg-card rounded-[22px] p-6 space-y-2">
+            <h1 class="text-2xl font-medium">
+                {request.memo.clone().unwrap_or_else(|| "Payment request".to_string())}
+            </h1>
+            <p class="text-[36px] font-semibold leading-none">{amount} " USDT"</p>
+            <p class="text-muted-foreground">"on " {chain_name}</p>
+            <p class="text-sm">
+                <AddressChunks address=request.recipient.clone() />
+            </p>
+            {request
+                .signed
+                .then(|| {
+                    view! {
+                        <span class="inline-block bg-black text-white text-sm font-medium px-3 py-0.5 rounded-full">
+                            "Amount and memo locked"
+                        </span>
+                    }
+                })}
+            {expiry}
+        </div>
+
+        <Show
+            when=move || !expired.get()
+            fallback=|| view! { <InvalidRequest err=PaymentRequestError::Expired /> }
+        >
+            <UntronWidget
+                initial=initial.clone()
+                fixed_recipient=true
+                fixed_receive_units=request.receive_units
+                on_order_created=open_order
+            />
+        </Show>
+    }
+}
+
+#[component]
+fn InvalidRequest(err: PaymentRequestError) -> impl IntoView {
+    view! {
+        <div class="bg-card rounded-[22px] p-6 space-y-2">
+            <h1 class="text-2xl font-medium">"Can't pay this request"</h1>
+            <p class="text-red-500">{err.to_string()}</p>
+        </div>
+    }
+}
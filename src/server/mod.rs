@@ -0,0 +1,42 @@
+//! The SSR server: renders `App` per request and serves the files the client
+//! loads at runtime.
+
+pub mod prerender;
+
+use axum::Router;
+use leptos::prelude::*;
+use leptos_axum::{generate_route_list, LeptosRoutes};
+use tower_http::services::{ServeDir, ServeFile};
+
+use crate::config::app::CONFIG_FILE;
+use crate::{shell, App};
+
+/// Images and content files, served under `/public`.
+const PUBLIC_DIR: &str = "public";
+
+/// Provided while pre-rendering, so nothing that goes stale (like `/info`)
+/// is baked into the generated files.
+#[derive(Clone, Copy)]
+pub struct Prerendering;
+
+pub fn router(options: LeptosOptions, prerendering: bool) -> Router {
+    let routes = generate_route_list(App);
+    Router::new()
+        .leptos_routes_with_context(
+            &options,
+            routes,
+            move || {
+                if prerendering {
+                    provide_context(Prerendering);
+                }
+            },
+            {
+                let options = options.clone();
+                move || shell(options.clone())
+            },
+        )
+        .nest_service("/public", ServeDir::new(PUBLIC_DIR))
+        .route_service("/config.json", ServeFile::new(CONFIG_FILE))
+        .fallback(leptos_axum::file_and_error_handler(shell))
+        .with_state(options)
+}
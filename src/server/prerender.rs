@@ -0,0 +1,93 @@
+//! Renders the routes without parameters to HTML files in the site root, so a
+//! plain static host serves crawlable pages that the `hydrate` bundle then
+//! takes over. The other routes get [`client_shell`], rendered in the browser.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use leptos::prelude::*;
+use leptos_axum::generate_route_list;
+use tower::ServiceExt;
+
+use super::{router, PUBLIC_DIR};
+use crate::config::app::CONFIG_FILE;
+use crate::{client_shell, App};
+
+/// Routes rendered entirely from their query string.
+const QUERY_ONLY_ROUTES: &[&str] = &["/pay"];
+
+/// Page static hosts serve for paths without a file, e.g. `/order/<id>`.
+const FALLBACK_PAGE: &str = "404.html";
+
+/// Writes `<route>/index.html` for every static route, the client shell as
+/// `404.html`, plus the files pages load at runtime. Returns the pages written.
+pub async fn prerender(options: LeptosOptions) -> io::Result<Vec<PathBuf>> {
+    let site_root = PathBuf::from(options.site_root.as_ref());
+    let app = router(options.clone(), true);
+    let client_shell = Owner::new().with(|| client_shell(options).to_html());
+
+    let mut pages = Vec::new();
+    for route in generate_route_list(App) {
+        let path = route.path();
+        // Pages like `/order/:id` only exist once someone creates them, and
+        // `/pay` is nothing without its query: both render in the browser.
+        if path.contains(['{', ':', '*']) {
+            continue;
+        }
+        if QUERY_ONLY_ROUTES.contains(&path) {
+            pages.push(write_page(&site_root, path, client_shell.as_bytes())?);
+            continue;
+        }
+        let request = Request::get(path)
+            .body(Body::empty())
+            .map_err(io::Error::other)?;
+        let response = app
+            .clone()
+            .oneshot(request)
+            .await
+            .map_err(io::Error::other)?;
+        if response.status() != StatusCode::OK {
+            return Err(io::Error::other(format!("{path}: {}", response.status())));
+        }
+        let html = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .map_err(io::Error::other)?;
+
+        pages.push(write_page(&site_root, path, &html)?);
+    }
+
+    let fallback = site_root.join(FALLBACK_PAGE);
+    fs::write(&fallback, &client_shell)?;
+    pages.push(fallback);
+
+    copy_dir(Path::new(PUBLIC_DIR), &site_root.join(PUBLIC_DIR))?;
+    fs::copy(CONFIG_FILE, site_root.join(CONFIG_FILE))?;
+    Ok(pages)
+}
+
+/// Writes `html` to `<path>/index.html` in the site root.
+fn write_page(site_root: &Path, path: &str, html: &[u8]) -> io::Result<PathBuf> {
+    let page = site_root
+        .join(path.trim_start_matches('/'))
+        .join("index.html");
+    fs::create_dir_all(page.parent().expect("page is inside the site root"))?;
+    fs::write(&page, html)?;
+    Ok(page)
+}
+
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
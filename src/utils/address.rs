@@ -0,0 +1,115 @@
+/// Whether `addr` looks like an EVM address: `0x` followed by 40 hex digits.
+/// The checksum (mixed case) isn't verified.
+pub fn is_valid_evm_address(addr: &str) -> bool {
+    addr.len() == 42
+        && addr.starts_with("0x")
+        && addr.chars().skip(2).all(|c| c.is_ascii_hexdigit())
+}
+
+/// Hex digits compared at each end by [`is_lookalike`]: what
+/// [`short_address`] shows.
+const LOOKALIKE_CHARS: usize = 4;
+
+/// Hex digits per group in [`address_chunks`].
+const CHUNK_CHARS: usize = 4;
+
+/// `0xAbCd…1234`: a valid address's first and last four hex digits. Anything
+/// else is returned unchanged.
+pub fn short_address(addr: &str) -> String {
+    if !is_valid_evm_address(addr) {
+        return addr.to_string();
+    }
+    format!(
+        "{}…{}",
+        &addr[..2 + LOOKALIKE_CHARS],
+        &addr[42 - LOOKALIKE_CHARS..]
+    )
+}
+
+/// The hex digits of a valid address in groups of four, so it can be
+/// compared group by group. Empty for anything else.
+pub fn address_chunks(addr: &str) -> Vec<&str> {
+    if !is_valid_evm_address(addr) {
+        return Vec::new();
+    }
+    (2..42)
+        .step_by(CHUNK_CHARS)
+        .map(|i| &addr[i..i + CHUNK_CHARS])
+        .collect()
+}
+
+/// Whether `addr` could pass for `known` at a glance: same start and end, but
+/// a different address. Address-poisoning scams send dust from such addresses
+/// so they show up in the victim's history.
+pub fn is_lookalike(addr: &str, known: &str) -> bool {
+    if !is_valid_evm_address(addr) || !is_valid_evm_address(known) {
+        return false;
+    }
+    let (addr, known) = (addr[2..].to_lowercase(), known[2..].to_lowercase());
+    addr != known
+        && addr[..LOOKALIKE_CHARS] == known[..LOOKALIKE_CHARS]
+        && addr[40 - LOOKALIKE_CHARS..] == known[40 - LOOKALIKE_CHARS..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_evm_addresses() {
+        assert!(is_valid_evm_address(
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        ));
+        assert!(is_valid_evm_address(
+            "0x0000000000000000000000000000000000000000"
+        ));
+    }
+
+    #[test]
+    fn rejects_everything_else() {
+        for addr in [
+            "",
+            "0x",
+            "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAe",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAedd",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeg",
+            "0X5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "TMockDepositAddress1111111111111111",
+            "vitalik.eth",
+        ] {
+            assert!(!is_valid_evm_address(addr), "{addr:?}");
+        }
+    }
+
+    #[test]
+    fn shortens_and_groups_addresses() {
+        let addr = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        assert_eq!(short_address(addr), "0x5aAe…eAed");
+        assert_eq!(short_address("vitalik.eth"), "vitalik.eth");
+        let chunks = address_chunks(addr);
+        assert_eq!(chunks.len(), 10);
+        assert_eq!(chunks[0], "5aAe");
+        assert_eq!(chunks.concat(), &addr[2..]);
+        assert!(address_chunks("0x12").is_empty());
+    }
+
+    #[test]
+    fn detects_lookalike_addresses() {
+        let known = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        assert!(is_lookalike(
+            &format!("0x5aaeb{}beaed", "0".repeat(30)),
+            known
+        ));
+        // The same address, whatever the case, isn't a lookalike.
+        assert!(!is_lookalike(&known.to_lowercase(), known));
+        assert!(!is_lookalike(
+            "0x1aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            known
+        ));
+        assert!(!is_lookalike(
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAe0",
+            known
+        ));
+    }
+}
@@ -0,0 +1,41 @@
+//! Moving data in and out of the browser as files.
+
+use std::time::Duration;
+
+use leptos::prelude::set_timeout;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Blob, BlobPropertyBag, File, HtmlAnchorElement, Url};
+
+/// How long the download link's URL stays valid. Firefox and Safari start the
+/// download after `click()` returns, so revoking it right away cancels it.
+const REVOKE_DELAY: Duration = Duration::from_secs(60);
+
+/// Saves `contents` as `file_name` through a temporary download link.
+pub fn download(file_name: &str, mime_type: &str, contents: &str) -> Result<(), JsValue> {
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let parts = js_sys::Array::of1(&JsValue::from_str(contents));
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or("no document")?;
+    let link: HtmlAnchorElement = document.create_element("a")?.unchecked_into();
+    link.set_href(&url);
+    link.set_download(file_name);
+    link.click();
+    set_timeout(
+        move || {
+            let _ = Url::revoke_object_url(&url);
+        },
+        REVOKE_DELAY,
+    );
+    Ok(())
+}
+
+/// Text of a file the user picked, or `None` if it can't be read.
+pub async fn read_text(file: &File) -> Option<String> {
+    JsFuture::from(file.text()).await.ok()?.as_string()
+}
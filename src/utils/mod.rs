@@ -1 +1,9 @@
+pub mod address;
+pub mod files;
+pub mod markup;
+pub mod payment_request;
+pub mod payout_csv;
+pub mod quote;
+pub mod swap_link;
+pub mod time;
 pub mod units;
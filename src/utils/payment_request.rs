@@ -0,0 +1,300 @@
+//! Merchant payment requests (`/pay?to=…&amount=…`): a fixed recipient and
+//! receive amount, with an optional memo and expiry.
+//!
+//! A request may be signed by its recipient (`sig`), an EIP-191
+//! `personal_sign` of [`PaymentRequest::message`], so the amount and memo
+//! can't be changed on the way to the customer. The signer is the `to` of
+//! the same link, so a signature doesn't say who made the link.
+
+use std::fmt;
+
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use sha3::{Digest, Keccak256};
+
+use super::address::is_valid_evm_address;
+use super::units::{string_to_units, units_to_string};
+
+/// Longest memo accepted, in characters.
+const MAX_MEMO_CHARS: usize = 140;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaymentRequest {
+    pub recipient: String,
+    /// Amount the recipient gets, in units.
+    pub receive_units: u64,
+    /// Destination chain id.
+    pub chain: u32,
+    pub memo: Option<String>,
+    /// Unix time (seconds) from which the request can no longer be paid.
+    pub expires_at: Option<u64>,
+    /// Whether the recipient signed the request.
+    pub signed: bool,
+}
+
+/// Why a payment link can't be paid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PaymentRequestError {
+    InvalidRecipient,
+    InvalidAmount,
+    UnsupportedChain,
+    MemoTooLong,
+    InvalidExpiry,
+    Expired,
+    /// `sig` is malformed or wasn't made by the recipient.
+    BadSignature,
+}
+
+impl fmt::Display for PaymentRequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidRecipient => "The payment link has no valid recipient address.",
+            Self::InvalidAmount => "The payment link has no valid amount.",
+            Self::UnsupportedChain => "The payment link asks for a chain Untron doesn't support.",
+            Self::MemoTooLong => "The payment link's note is too long.",
+            Self::InvalidExpiry => "The payment link has an invalid expiry time.",
+            Self::Expired => "This payment request has expired. Ask the merchant for a new link.",
+            Self::BadSignature => {
+                "This payment link was altered or wasn't signed by its recipient. Don't pay it."
+            }
+        })
+    }
+}
+
+impl PaymentRequest {
+    /// Reads a request from the query param getter `get`. The chain defaults
+    /// to the first of `chain_ids`; `now` is the current Unix time in seconds.
+    pub fn from_query(
+        get: impl Fn(&str) -> Option<String>,
+        chain_ids: &[u32],
+        now: u64,
+    ) -> Result<Self, PaymentRequestError> {
+        use PaymentRequestError::*;
+
+        let recipient = get("to")
+            .map(|to| to.trim().to_string())
+            .filter(|to| is_valid_evm_address(to))
+            .ok_or(InvalidRecipient)?;
+        let receive_units = get("amount")
+            .and_then(|amount| string_to_units(amount.trim()))
+            .filter(|&units| units > 0)
+            .ok_or(InvalidAmount)?;
+        let chain = match get("chain") {
+            Some(id) => id.parse().ok().filter(|id| chain_ids.contains(id)),
+            None => chain_ids.first().copied(),
+        }
+        .ok_or(UnsupportedChain)?;
+        // Kept as given: it's part of the signed message.
+        let memo = get("memo").filter(|memo| !memo.is_empty());
+        if memo
+            .as_ref()
+            .is_some_and(|memo| memo.chars().count() > MAX_MEMO_CHARS)
+        {
+            return Err(MemoTooLong);
+        }
+        let expires_at = get("expires")
+            .map(|at| at.parse().map_err(|_| InvalidExpiry))
+            .transpose()?;
+
+        let mut request = Self {
+            recipient,
+            receive_units,
+            chain,
+            memo,
+            expires_at,
+            signed: false,
+        };
+        if let Some(sig) = get("sig") {
+            let signer = recover_signer(&request.message(), &sig).ok_or(BadSignature)?;
+            if !signer.eq_ignore_ascii_case(&request.recipient) {
+                return Err(BadSignature);
+            }
+            request.signed = true;
+        }
+        if request.is_expired(now) {
+            return Err(Expired);
+        }
+        Ok(request)
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|at| now >= at)
+    }
+
+    /// Text the recipient signs. Every field is part of it, so none can be
+    /// changed without invalidating the signature.
+    pub fn message(&self) -> String {
+        format!(
+            "Untron payment request\nto: {}\namount: {}\nchain: {}\nmemo: {}\nexpires: {}",
+            self.recipient.to_ascii_lowercase(),
+            units_to_string(self.receive_units),
+            self.chain,
+            self.memo.as_deref().unwrap_or_default(),
+            self.expires_at.map(|at| at.to_string()).unwrap_or_default(),
+        )
+    }
+}
+
+/// Hash signed by `personal_sign` (EIP-191).
+fn eip191_hash(message: &str) -> [u8; 32] {
+    Keccak256::new()
+        .chain_update(format!("\x19Ethereum Signed Message:\n{}", message.len()))
+        .chain_update(message)
+        .finalize()
+        .into()
+}
+
+/// Address (lowercase) whose key produced the 65-byte `r ‖ s ‖ v` signature
+/// `sig_hex` of `message`, if it's well-formed.
+fn recover_signer(message: &str, sig_hex: &str) -> Option<String> {
+    let bytes = decode_hex(sig_hex.strip_prefix("0x").unwrap_or(sig_hex))?;
+    let (sig, &[v]) = bytes.split_first_chunk::<64>()? else {
+        return None;
+    };
+    let signature = Signature::from_slice(sig).ok()?;
+    let recovery_id = RecoveryId::from_byte(if v >= 27 { v - 27 } else { v })?;
+    let key =
+        VerifyingKey::recover_from_prehash(&eip191_hash(message), &signature, recovery_id).ok()?;
+    Some(address_of(&key))
+}
+
+fn address_of(key: &VerifyingKey) -> String {
+    let point = key.to_encoded_point(false);
+    let hash = Keccak256::digest(&point.as_bytes()[1..]);
+    format!("0x{}", encode_hex(&hash[12..]))
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use k256::ecdsa::SigningKey;
+
+    use super::*;
+
+    const CHAINS: &[u32] = &[42161, 8453];
+    const NOW: u64 = 1_700_000_000;
+
+    fn query<'a>(pairs: &'a [(&str, &str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |key| {
+            pairs
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.to_string())
+        }
+    }
+
+    /// Signs `request` the way a wallet's `personal_sign` would.
+    fn sign(key: &SigningKey, request: &PaymentRequest) -> String {
+        let (sig, recovery_id) = key
+            .sign_prehash_recoverable(&eip191_hash(&request.message()))
+            .unwrap();
+        format!(
+            "0x{}{:02x}",
+            encode_hex(&sig.to_bytes()),
+            recovery_id.to_byte() + 27
+        )
+    }
+
+    #[test]
+    fn derives_ethereum_addresses() {
+        // Well-known address of the private key 1.
+        let key = SigningKey::from_slice(&[[0; 31].as_slice(), &[1]].concat()).unwrap();
+        assert_eq!(
+            address_of(key.verifying_key()),
+            "0x7e5f4552091a69125d5dfcb7b8c2659029395bdf"
+        );
+    }
+
+    #[test]
+    fn reads_plain_requests() {
+        let request = PaymentRequest::from_query(
+            query(&[
+                ("to", "0x1234567890abcdef1234567890abcdef12345678"),
+                ("amount", "25.5"),
+                ("memo", " Invoice #42 "),
+            ]),
+            CHAINS,
+            NOW,
+        )
+        .unwrap();
+        assert_eq!(request.receive_units, string_to_units("25.5").unwrap());
+        assert_eq!(request.chain, 42161);
+        assert_eq!(request.memo.as_deref(), Some(" Invoice #42 "));
+        assert!(!request.signed);
+    }
+
+    #[test]
+    fn rejects_invalid_requests() {
+        let to = ("to", "0x1234567890abcdef1234567890abcdef12345678");
+        for (pairs, err) in [
+            (vec![("amount", "1")], PaymentRequestError::InvalidRecipient),
+            (
+                vec![to, ("amount", "0")],
+                PaymentRequestError::InvalidAmount,
+            ),
+            (
+                vec![to, ("amount", "1"), ("chain", "1")],
+                PaymentRequestError::UnsupportedChain,
+            ),
+            (
+                vec![to, ("amount", "1"), ("expires", "1699999999")],
+                PaymentRequestError::Expired,
+            ),
+        ] {
+            assert_eq!(
+                PaymentRequest::from_query(query(&pairs), CHAINS, NOW),
+                Err(err)
+            );
+        }
+    }
+
+    #[test]
+    fn verifies_the_recipients_signature() {
+        let key = SigningKey::from_slice(&[7; 32]).unwrap();
+        let recipient = address_of(key.verifying_key());
+        let unsigned = PaymentRequest::from_query(
+            query(&[("to", &recipient), ("amount", "10"), ("memo", "Order 1")]),
+            CHAINS,
+            NOW,
+        )
+        .unwrap();
+        let sig = sign(&key, &unsigned);
+
+        let signed = PaymentRequest::from_query(
+            query(&[
+                ("to", &recipient),
+                ("amount", "10"),
+                ("memo", "Order 1"),
+                ("sig", &sig),
+            ]),
+            CHAINS,
+            NOW,
+        )
+        .unwrap();
+        assert!(signed.signed);
+
+        let tampered = PaymentRequest::from_query(
+            query(&[
+                ("to", &recipient),
+                ("amount", "1000"),
+                ("memo", "Order 1"),
+                ("sig", &sig),
+            ]),
+            CHAINS,
+            NOW,
+        );
+        assert_eq!(tampered, Err(PaymentRequestError::BadSignature));
+    }
+}
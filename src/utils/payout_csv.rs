@@ -0,0 +1,210 @@
+//! CSV files for batch payouts: `address,amount,chain` rows in, the same rows
+//! with their order ids out.
+
+use std::fmt;
+
+use super::address::is_valid_evm_address;
+use super::units::{string_to_units, units_to_string};
+
+/// One payout read from the uploaded file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PayoutRow {
+    /// 1-based line in the file, for error messages.
+    pub line: usize,
+    pub recipient: String,
+    /// Amount the recipient gets, in units.
+    pub receive_units: u64,
+    /// Destination chain id.
+    pub chain: u32,
+}
+
+/// A line that isn't a valid payout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RowError {
+    pub line: usize,
+    pub kind: RowErrorKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RowErrorKind {
+    MissingAmount,
+    InvalidAddress,
+    InvalidAmount,
+    UnsupportedChain,
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self.kind {
+            RowErrorKind::MissingAmount => "expected address,amount[,chain]",
+            RowErrorKind::InvalidAddress => "not a valid address",
+            RowErrorKind::InvalidAmount => "not a valid amount",
+            RowErrorKind::UnsupportedChain => "unsupported chain",
+        };
+        write!(f, "Line {}: {reason}", self.line)
+    }
+}
+
+/// Outcome of creating the order for a row.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum PayoutStatus {
+    #[default]
+    Pending,
+    /// Holds the order id.
+    Created(String),
+    /// Holds the error shown to the user.
+    Failed(String),
+}
+
+/// Reads `address,amount[,chain]` lines. The chain defaults to the first of
+/// `chain_ids`; blank lines, `#` comments and an `address,…` header are
+/// skipped.
+pub fn parse_payouts(csv: &str, chain_ids: &[u32]) -> Vec<Result<PayoutRow, RowError>> {
+    csv.lines()
+        .enumerate()
+        .map(|(i, text)| (i + 1, split_fields(text)))
+        .filter(|(_, fields)| match fields.first() {
+            None => false,
+            Some(first) => !first.starts_with('#') && !first.eq_ignore_ascii_case("address"),
+        })
+        .map(|(line, fields)| {
+            let error = |kind| RowError { line, kind };
+            let [recipient, amount, rest @ ..] = fields.as_slice() else {
+                return Err(error(RowErrorKind::MissingAmount));
+            };
+            if !is_valid_evm_address(recipient) {
+                return Err(error(RowErrorKind::InvalidAddress));
+            }
+            let receive_units = string_to_units(amount)
+                .filter(|&units| units > 0)
+                .ok_or(error(RowErrorKind::InvalidAmount))?;
+            let chain = match rest.first().filter(|chain| !chain.is_empty()) {
+                Some(chain) => chain.parse().ok().filter(|id| chain_ids.contains(id)),
+                None => chain_ids.first().copied(),
+            }
+            .ok_or(error(RowErrorKind::UnsupportedChain))?;
+            Ok(PayoutRow {
+                line,
+                recipient: recipient.clone(),
+                receive_units,
+                chain,
+            })
+        })
+        .collect()
+}
+
+/// The rows with their outcome, as CSV with a header.
+pub fn results_csv(rows: &[(PayoutRow, PayoutStatus)]) -> String {
+    let mut csv = String::from("address,amount,chain,status,order_id,error\n");
+    for (row, status) in rows {
+        let (status, id, error) = match status {
+            PayoutStatus::Pending => ("pending", "", ""),
+            PayoutStatus::Created(id) => ("created", id.as_str(), ""),
+            PayoutStatus::Failed(error) => ("failed", "", error.as_str()),
+        };
+        let fields = [
+            row.recipient.clone(),
+            units_to_string(row.receive_units),
+            row.chain.to_string(),
+            status.to_string(),
+            quote(id),
+            quote(error),
+        ];
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Trimmed fields of a line, with surrounding quotes removed. Blank lines have
+/// no fields.
+fn split_fields(line: &str) -> Vec<String> {
+    if line.trim().is_empty() {
+        return Vec::new();
+    }
+    line.split(',')
+        .map(|field| field.trim().trim_matches('"').trim().to_string())
+        .collect()
+}
+
+/// Quotes `field` if it would otherwise break the row.
+fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "0x1234567890abcdef1234567890abcdef12345678";
+    const CHAINS: &[u32] = &[42161, 8453];
+
+    #[test]
+    fn parses_rows_and_reports_errors_by_line() {
+        let csv = format!(
+            "address,amount,chain\n\
+             {ADDRESS},10.5\n\
+             \n\
+             # contractors\n\
+             \"{ADDRESS}\", 2 ,8453\n\
+             0x1234,1\n\
+             {ADDRESS},0\n\
+             {ADDRESS},1,1\n\
+             {ADDRESS}\n"
+        );
+        let rows = parse_payouts(&csv, CHAINS);
+        assert_eq!(
+            rows[0],
+            Ok(PayoutRow {
+                line: 2,
+                recipient: ADDRESS.to_string(),
+                receive_units: string_to_units("10.5").unwrap(),
+                chain: 42161,
+            })
+        );
+        assert_eq!(rows[1].as_ref().map(|row| row.chain), Ok(8453));
+        let errors = rows[2..]
+            .iter()
+            .map(|row| row.clone().unwrap_err())
+            .map(|err| (err.line, err.kind))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                (6, RowErrorKind::InvalidAddress),
+                (7, RowErrorKind::InvalidAmount),
+                (8, RowErrorKind::UnsupportedChain),
+                (9, RowErrorKind::MissingAmount),
+            ]
+        );
+    }
+
+    #[test]
+    fn exports_results_with_order_ids() {
+        let row = PayoutRow {
+            line: 1,
+            recipient: ADDRESS.to_string(),
+            receive_units: string_to_units("3").unwrap(),
+            chain: 42161,
+        };
+        let csv = results_csv(&[
+            (row.clone(), PayoutStatus::Created("ord_1".to_string())),
+            (
+                row,
+                PayoutStatus::Failed("Amount too low, \"min\" 5".to_string()),
+            ),
+        ]);
+        assert_eq!(
+            csv,
+            format!(
+                "address,amount,chain,status,order_id,error\n\
+                 {ADDRESS},3,42161,created,ord_1,\n\
+                 {ADDRESS},3,42161,failed,,\"Amount too low, \"\"min\"\" 5\"\n"
+            )
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use super::units::*;
+
+/// Which of the two currency inputs an amount was typed into.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum QuoteSide {
+    #[default]
+    Send,
+    Receive,
+}
+
+/// Two-way quote shared by the send and receive inputs.
+///
+/// Only the side the user edited last is stored, verbatim; the other side is
+/// derived from it on every read. Rounding therefore only ever affects the
+/// derived side and never rewrites what the user typed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Quote {
+    /// Side the user edited last.
+    pub side: QuoteSide,
+    /// Text of that side exactly as typed (or as clamped).
+    pub input: String,
+    /// Whether the last edit was clamped to the maximum order output.
+    pub clamped: bool,
+}
+
+impl Quote {
+    /// Applies an edit of `side`. `max_units` caps the receive amount.
+    ///
+    /// Returns `None` when `raw` isn't a valid amount, in which case the
+    /// previous quote should be kept.
+    pub fn edit(
+        side: QuoteSide,
+        raw: &str,
+        swap_rate_units: u64,
+        max_units: Option<u64>,
+    ) -> Option<Self> {
+        if raw.is_empty() {
+            return Some(Self {
+                side,
+                ..Self::default()
+            });
+        }
+
+        let units = string_to_units(raw)?;
+        let receive_units = match side {
+            QuoteSide::Send => convert_send_to_receive(units, swap_rate_units),
+            QuoteSide::Receive => units,
+        };
+
+        match max_units {
+            Some(max) if receive_units > max => {
+                let clamped_units = match side {
+                    QuoteSide::Send => max_send_for_receive(max, swap_rate_units),
+                    QuoteSide::Receive => max,
+                };
+                Some(Self {
+                    side,
+                    input: units_to_string(clamped_units),
+                    clamped: true,
+                })
+            }
+            _ => Some(Self {
+                side,
+                input: raw.to_string(),
+                clamped: false,
+            }),
+        }
+    }
+
+    /// Text to display in the input for `side`.
+    pub fn text(&self, side: QuoteSide, swap_rate_units: u64) -> String {
+        if side == self.side {
+            return self.input.clone();
+        }
+        self.units(side, swap_rate_units)
+            .map(units_to_string)
+            .unwrap_or_default()
+    }
+
+    /// Amount for `side` in units, if the quote holds a valid amount.
+    pub fn units(&self, side: QuoteSide, swap_rate_units: u64) -> Option<u64> {
+        if self.input.is_empty() {
+            return None;
+        }
+        let units = string_to_units(&self.input)?;
+        Some(match (self.side, side) {
+            (QuoteSide::Send, QuoteSide::Receive) => {
+                convert_send_to_receive(units, swap_rate_units)
+            }
+            (QuoteSide::Receive, QuoteSide::Send) => {
+                convert_receive_to_send(units, swap_rate_units)
+            }
+            _ => units,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(side: QuoteSide, raw: &str) -> Quote {
+        Quote::edit(side, raw, SWAP_RATE_UNITS, None).expect("valid amount")
+    }
+
+    #[test]
+    fn send_edit_keeps_typed_text_and_derives_receive() {
+        let quote = edit(QuoteSide::Send, "100");
+        assert_eq!(quote.text(QuoteSide::Send, SWAP_RATE_UNITS), "100");
+        assert_eq!(quote.text(QuoteSide::Receive, SWAP_RATE_UNITS), "99.97");
+    }
+
+    #[test]
+    fn receive_edit_keeps_typed_text_and_derives_send() {
+        let quote = edit(QuoteSide::Receive, "100");
+        assert_eq!(quote.text(QuoteSide::Receive, SWAP_RATE_UNITS), "100");
+        assert_eq!(quote.text(QuoteSide::Send, SWAP_RATE_UNITS), "100.030009");
+    }
+
+    #[test]
+    fn typed_formatting_is_preserved() {
+        for raw in ["1.50", "007", "0.", ".5"] {
+            assert_eq!(
+                edit(QuoteSide::Receive, raw).text(QuoteSide::Receive, SWAP_RATE_UNITS),
+                raw
+            );
+            assert_eq!(
+                edit(QuoteSide::Send, raw).text(QuoteSide::Send, SWAP_RATE_UNITS),
+                raw
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_rejected() {
+        assert_eq!(
+            Quote::edit(QuoteSide::Send, "1.2.3", SWAP_RATE_UNITS, None),
+            None
+        );
+        assert_eq!(
+            Quote::edit(QuoteSide::Receive, "0.1234567", SWAP_RATE_UNITS, None),
+            None
+        );
+    }
+
+    #[test]
+    fn empty_input_clears_both_sides() {
+        let quote = edit(QuoteSide::Receive, "");
+        assert_eq!(quote.text(QuoteSide::Send, SWAP_RATE_UNITS), "");
+        assert_eq!(quote.units(QuoteSide::Receive, SWAP_RATE_UNITS), None);
+    }
+
+    #[test]
+    fn edits_above_max_are_clamped_on_the_edited_side() {
+        let max = string_to_units("50").unwrap();
+
+        let quote = Quote::edit(QuoteSide::Receive, "60", SWAP_RATE_UNITS, Some(max)).unwrap();
+        assert!(quote.clamped);
+        assert_eq!(quote.text(QuoteSide::Receive, SWAP_RATE_UNITS), "50");
+
+        let quote = Quote::edit(QuoteSide::Send, "60", SWAP_RATE_UNITS, Some(max)).unwrap();
+        assert!(quote.clamped);
+        assert_eq!(quote.side, QuoteSide::Send);
+        assert_eq!(quote.units(QuoteSide::Receive, SWAP_RATE_UNITS), Some(max));
+    }
+}
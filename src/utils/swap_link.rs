@@ -0,0 +1,113 @@
+//! Swap form state carried in the URL (`/?amount=…&to=…&chain=…`), so a
+//! prefilled swap can be bookmarked or shared.
+
+use super::address::is_valid_evm_address;
+use super::units::string_to_units;
+
+const AMOUNT: &str = "amount";
+const TO: &str = "to";
+const CHAIN: &str = "chain";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SwapLink {
+    /// Amount to send, as typed (e.g. `"12.5"`).
+    pub amount: Option<String>,
+    /// Recipient address.
+    pub to: Option<String>,
+    /// Destination chain id; `None` for the default chain.
+    pub chain: Option<u32>,
+}
+
+impl SwapLink {
+    /// Reads the link from the query param getter `get`, keeping only values
+    /// that pass [`SwapLink::validated`].
+    pub fn from_query(get: impl Fn(&str) -> Option<String>, chain_ids: &[u32]) -> Self {
+        Self {
+            amount: get(AMOUNT),
+            to: get(TO),
+            chain: get(CHAIN).and_then(|id| id.parse().ok()),
+        }
+        .validated(chain_ids)
+    }
+
+    /// Drops values that wouldn't pass the form's own validation, and chains
+    /// not in `chain_ids`.
+    pub fn validated(self, chain_ids: &[u32]) -> Self {
+        let amount = self
+            .amount
+            .map(|amount| amount.trim().to_string())
+            .filter(|amount| string_to_units(amount).is_some());
+        let to = self
+            .to
+            .map(|to| to.trim().to_string())
+            .filter(|to| is_valid_evm_address(to));
+        let chain = self.chain.filter(|id| chain_ids.contains(id));
+        Self { amount, to, chain }
+    }
+
+    /// Query params to set, or to remove when `None`. Other params in the URL
+    /// are left alone.
+    pub fn params(&self) -> [(&'static str, Option<String>); 3] {
+        [
+            (AMOUNT, self.amount.clone()),
+            (TO, self.to.clone()),
+            (CHAIN, self.chain.map(|id| id.to_string())),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "0x1234567890abcdef1234567890abcdef12345678";
+
+    fn query<'a>(pairs: &'a [(&str, &str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |key| {
+            pairs
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.to_string())
+        }
+    }
+
+    #[test]
+    fn reads_valid_params() {
+        let link = SwapLink::from_query(
+            query(&[("amount", " 12.5"), ("to", ADDRESS), ("chain", "42161")]),
+            &[42161],
+        );
+        assert_eq!(
+            link,
+            SwapLink {
+                amount: Some("12.5".into()),
+                to: Some(ADDRESS.into()),
+                chain: Some(42161),
+            }
+        );
+    }
+
+    #[test]
+    fn drops_invalid_params() {
+        let link = SwapLink::from_query(
+            query(&[("amount", "-1"), ("to", "0xAbCd…1234"), ("chain", "1")]),
+            &[42161],
+        );
+        assert_eq!(link, SwapLink::default());
+    }
+
+    #[test]
+    fn params_round_trip() {
+        let link = SwapLink {
+            amount: Some("3".into()),
+            to: None,
+            chain: Some(42161),
+        };
+        let params = link.params().map(|(k, v)| (k, v.unwrap_or_default()));
+        let params = params
+            .iter()
+            .map(|(k, v)| (*k, v.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(SwapLink::from_query(query(&params), &[42161]), link);
+    }
+}
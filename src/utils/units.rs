@@ -21,6 +21,15 @@ pub fn string_to_units(value: &str) -> Option<u64> {
         return None;
     }
 
+    // Only digits; `u64::from_str` alone would also accept a leading `+`.
+    if !whole
+        .chars()
+        .chain(frac.chars())
+        .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+
     // Reject more fractional digits than supported.
     if frac.len() > DEFAULT_DECIMALS as usize {
         return None;
@@ -32,7 +41,7 @@ pub fn string_to_units(value: &str) -> Option<u64> {
     scaled.push_str(whole.trim_start_matches('0'));
     let padding = DEFAULT_DECIMALS as usize - frac.len();
     scaled.push_str(frac);
-    scaled.extend(std::iter::repeat('0').take(padding));
+    scaled.extend(std::iter::repeat_n('0', padding));
 
     // Empty string after trimming => value was 0.
     let scaled = if scaled.is_empty() { "0" } else { &scaled };
@@ -82,3 +91,85 @@ pub fn convert_send_to_receive(send_units: u64, swap_rate_units: u64) -> u64 {
         .saturating_add(RATE_SCALE / 2)
         / RATE_SCALE
 }
+
+/// Calculates the protocol fee (in send units) kept when swapping `send_units`.
+pub fn protocol_fee(send_units: u64, swap_rate_units: u64) -> u64 {
+    send_units.saturating_sub(convert_send_to_receive(send_units, swap_rate_units))
+}
+
+/// Calculates the largest send amount whose receive amount doesn't exceed `max_receive_units`.
+pub fn max_send_for_receive(max_receive_units: u64, swap_rate_units: u64) -> u64 {
+    // convert_receive_to_send rounds to nearest, so it may overshoot by a unit or two.
+    let mut send_units = convert_receive_to_send(max_receive_units, swap_rate_units);
+    while send_units > 0 && convert_send_to_receive(send_units, swap_rate_units) > max_receive_units
+    {
+        send_units -= 1;
+    }
+    send_units
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_decimal_strings() {
+        assert_eq!(string_to_units("1"), Some(1_000_000));
+        assert_eq!(string_to_units("1.5"), Some(1_500_000));
+        assert_eq!(string_to_units("0.000001"), Some(1));
+        assert_eq!(string_to_units(".5"), Some(500_000));
+        assert_eq!(string_to_units("007."), Some(7_000_000));
+        assert_eq!(string_to_units(""), Some(0));
+    }
+
+    #[test]
+    fn rejects_malformed_strings() {
+        for raw in ["1.2.3", "0.0000001", "abc", "-1", "+1", ".+5", "1e6", " 1"] {
+            assert_eq!(string_to_units(raw), None, "{raw:?}");
+        }
+        // Overflows u64 once scaled.
+        assert_eq!(string_to_units("18446744073710"), None);
+    }
+
+    #[test]
+    fn formats_units() {
+        assert_eq!(units_to_string(0), "0");
+        assert_eq!(units_to_string(1), "0.000001");
+        assert_eq!(units_to_string(1_500_000), "1.5");
+        assert_eq!(units_to_string(250_000_000_000), "250000");
+    }
+
+    #[test]
+    fn round_trips() {
+        for units in [0, 1, 999_999, 1_000_000, 123_456_789, u64::MAX] {
+            assert_eq!(string_to_units(&units_to_string(units)), Some(units));
+        }
+    }
+
+    #[test]
+    fn converts_between_send_and_receive() {
+        let hundred = 100_000_000;
+        assert_eq!(
+            convert_send_to_receive(hundred, SWAP_RATE_UNITS),
+            99_970_000
+        );
+        assert_eq!(
+            convert_receive_to_send(hundred, SWAP_RATE_UNITS),
+            100_030_009
+        );
+        assert_eq!(protocol_fee(hundred, SWAP_RATE_UNITS), 30_000);
+        assert_eq!(
+            convert_send_to_receive(u64::MAX, SWAP_RATE_UNITS),
+            u64::MAX / RATE_SCALE
+        );
+    }
+
+    #[test]
+    fn max_send_never_exceeds_max_receive() {
+        for max in [1, 2, 3, 999, 1_000_000, 123_456_789] {
+            let send = max_send_for_receive(max, SWAP_RATE_UNITS);
+            assert!(convert_send_to_receive(send, SWAP_RATE_UNITS) <= max);
+            assert!(convert_send_to_receive(send + 1, SWAP_RATE_UNITS) > max);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! The visitor's own wallets, read from the providers their extensions inject:
+//! TronLink's `window.tronWeb` and an EVM wallet's `window.ethereum`. Nothing
+//! here asks for access: values only appear once the visitor has connected a
+//! wallet to the site.
+
+use std::time::Duration;
+
+use js_sys::{Array, Function, Object, Promise, Reflect};
+use leptos::prelude::*;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+
+use crate::utils::address::is_valid_evm_address;
+
+/// USDT (TRC-20) contract on Tron mainnet.
+const USDT_CONTRACT: &str = "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t";
+
+/// How often the wallets are read again: extensions inject themselves after
+/// the page loads, and the visitor can switch accounts at any time.
+const WALLET_REFRESH_INTERVAL: Duration = Duration::from_secs(15);
+
+/// The user's connected wallets, shared through context.
+///
+/// Fields stay `None` until a wallet is connected and queried; consumers must
+/// treat them as optional hints.
+#[derive(Clone, Copy, Default)]
+pub struct TronWallet {
+    /// USDT (TRC-20) balance of the connected account, in units.
+    pub usdt_balance: RwSignal<Option<u64>>,
+    /// First account the injected EVM wallet has shared with the site.
+    pub evm_address: RwSignal<Option<String>>,
+}
+
+impl TronWallet {
+    /// Wallet state kept in sync with the injected provider. Effects only run
+    /// in the browser, so the server never polls.
+    pub fn new() -> Self {
+        let wallet = Self::default();
+        Effect::new(move |_| {
+            let refresh = move || {
+                spawn_local(async move {
+                    let _ = wallet.usdt_balance.try_set(usdt_balance().await);
+                    let _ = wallet.evm_address.try_set(evm_address().await);
+                })
+            };
+            refresh();
+            if let Ok(handle) = set_interval_with_handle(refresh, WALLET_REFRESH_INTERVAL) {
+                on_cleanup(move || handle.clear());
+            }
+        });
+        wallet
+    }
+}
+
+/// Wallet state provided by `App`.
+pub fn use_tron_wallet() -> TronWallet {
+    use_context::<TronWallet>().expect("TronWallet is provided by App")
+}
+
+// ---- TronWeb --------------------------------------------------------------
+
+/// USDT balance of the account TronLink is connected with, if any.
+async fn usdt_balance() -> Option<u64> {
+    let tron_web = get(&window(), "tronWeb").filter(JsValue::is_object)?;
+    // `false` until the visitor connects the site.
+    let address = get(&tron_web, "defaultAddress")
+        .and_then(|address| get(&address, "base58"))
+        .and_then(|address| address.as_string())?;
+
+    let contract = call(&tron_web, "contract", &[])?;
+    let contract = resolve(call(&contract, "at", &[USDT_CONTRACT.into()])?).await?;
+    let balance_of = call(&contract, "balanceOf", &[address.into()])?;
+    let balance = resolve(call(&balance_of, "call", &[])?).await?;
+    // A BigNumber or a BigInt, depending on the TronWeb version; both print
+    // in base 10.
+    call(&balance, "toString", &[])?.as_string()?.parse().ok()
+}
+
+// ---- EIP-1193 -------------------------------------------------------------
+
+/// First account the EVM wallet has already shared. `eth_accounts`, unlike
+/// `eth_requestAccounts`, never opens a prompt.
+async fn evm_address() -> Option<String> {
+    let ethereum = get(&window(), "ethereum").filter(JsValue::is_object)?;
+    let args = Object::new();
+    Reflect::set(&args, &"method".into(), &"eth_accounts".into()).ok()?;
+    let accounts = resolve(call(&ethereum, "request", &[args.into()])?).await?;
+    Array::from(&accounts)
+        .get(0)
+        .as_string()
+        .filter(|address| is_valid_evm_address(address))
+}
+
+// ---- Helpers --------------------------------------------------------------
+
+fn get(target: &JsValue, key: &str) -> Option<JsValue> {
+    Reflect::get(target, &key.into())
+        .ok()
+        .filter(|value| !value.is_undefined() && !value.is_null())
+}
+
+/// Calls `target.method(...args)`, if `method` is a function that doesn't throw.
+fn call(target: &JsValue, method: &str, args: &[JsValue]) -> Option<JsValue> {
+    let function = get(target, method)?.dyn_into::<Function>().ok()?;
+    function.apply(target, &args.iter().collect::<Array>()).ok()
+}
+
+/// Awaits `value` if it's a promise.
+async fn resolve(value: JsValue) -> Option<JsValue> {
+    JsFuture::from(Promise::resolve(&value)).await.ok()
+}
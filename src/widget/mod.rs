@@ -0,0 +1,200 @@
+//! Entry point of the embeddable build (`--features widget`): partners mount
+//! the swap form into an element on their own page.
+//!
+//! ```js
+//! import init, { mountUntronWidget } from "./pkg/untron_frontend.js";
+//!
+//! await init();
+//! const widget = mountUntronWidget(document.getElementById("untron"), {
+//!   recipient: "0x…",
+//!   theme: "dark",
+//! });
+//! window.addEventListener("message", (event) => {
+//!   if (event.data?.type === "untron:orderCompleted") { /* … */ }
+//! });
+//! ```
+
+use leptos::logging;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use web_sys::HtmlElement;
+
+use crate::api::order::{Order, OrderStatus};
+use crate::components::order::tracker::OrderTracker;
+use crate::components::untron_widget::UntronWidget;
+use crate::config::app::AppConfig;
+use crate::i18n::Locale;
+use crate::utils::swap_link::SwapLink;
+use crate::wallet::TronWallet;
+
+/// Options the host page passes to `mountUntronWidget`; all optional.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+struct WidgetConfig {
+    /// Backend orders are created with, instead of the production API.
+    api_base_url: Option<String>,
+    /// Address every order pays out to; visitors can't change it.
+    recipient: Option<String>,
+    /// Destination chain id; one of the chains the app supports.
+    chain: Option<u32>,
+    /// Amount to send the form starts with.
+    amount: Option<String>,
+    theme: Theme,
+    /// Origin order messages are posted to. Required when the widget is
+    /// framed; the page's own origin otherwise.
+    target_origin: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Theme {
+    #[default]
+    Light,
+    Dark,
+}
+
+/// Messages posted to the host: the parent window when the widget is
+/// framed, the page itself otherwise.
+#[derive(Serialize)]
+#[serde(tag = "type")]
+enum WidgetMessage<'a> {
+    #[serde(rename = "untron:orderCreated")]
+    OrderCreated { id: &'a str },
+    #[serde(rename = "untron:orderCompleted")]
+    OrderCompleted { order: &'a Order },
+}
+
+/// Returned by `mountUntronWidget`; the widget stays mounted until
+/// `unmount()` is called.
+#[wasm_bindgen]
+pub struct UntronWidgetHandle {
+    mounted: Box<dyn std::any::Any>,
+}
+
+#[wasm_bindgen]
+impl UntronWidgetHandle {
+    pub fn unmount(self) {
+        drop(self.mounted);
+    }
+}
+
+/// Mounts the swap form into `element`. `config` is a plain object (see
+/// `WidgetConfig`); invalid options are reported instead of ignored.
+///
+/// The widget runs on the compiled `AppConfig` defaults: the site's
+/// `config.json` isn't read, so its feature switches don't apply.
+#[wasm_bindgen(js_name = mountUntronWidget)]
+pub fn mount_untron_widget(
+    element: HtmlElement,
+    config: JsValue,
+) -> Result<UntronWidgetHandle, JsError> {
+    console_error_panic_hook::set_once();
+
+    let config: WidgetConfig = if config.is_undefined() || config.is_null() {
+        WidgetConfig::default()
+    } else {
+        let json = js_sys::JSON::stringify(&config)
+            .map_err(|_| JsError::new("config must be a plain object"))?;
+        serde_json::from_str(&String::from(json))
+            .map_err(|err| JsError::new(&format!("invalid config: {err}")))?
+    };
+
+    let mut app_config = AppConfig::default();
+    if let Some(url) = config.api_base_url {
+        app_config.api_base_url = url.trim_end_matches('/').to_string();
+    }
+    let chain_ids = app_config.chains.iter().map(|c| c.id).collect::<Vec<_>>();
+    let initial = SwapLink {
+        amount: config.amount,
+        to: config.recipient.clone(),
+        chain: config.chain,
+    }
+    .validated(&chain_ids);
+    if config.recipient.is_some() && initial.to.is_none() {
+        return Err(JsError::new("recipient isn't an EVM address"));
+    }
+    if config.chain.is_some() && initial.chain.is_none() {
+        return Err(JsError::new("chain isn't supported"));
+    }
+    // The parent's origin is unknown (and unreadable) from inside a frame.
+    let target_origin = match config.target_origin {
+        Some(origin) => origin,
+        None if host_window().is_some() => {
+            return Err(JsError::new("targetOrigin is required when framed"));
+        }
+        None => window()
+            .location()
+            .origin()
+            .map_err(|_| JsError::new("couldn't read the page origin"))?,
+    };
+
+    let theme = config.theme;
+    let mounted = leptos::mount::mount_to(element, move || {
+        provide_context(app_config);
+        provide_context(Locale::detect());
+        provide_context(TronWallet::new());
+        view! { <EmbeddedWidget initial theme target_origin /> }
+    });
+    Ok(UntronWidgetHandle {
+        mounted: Box::new(mounted),
+    })
+}
+
+/// The swap form, replaced by the order's status once one is created.
+#[component]
+fn EmbeddedWidget(initial: SwapLink, theme: Theme, target_origin: String) -> impl IntoView {
+    let fixed_recipient = initial.to.is_some();
+    let initial = StoredValue::new(initial);
+    let target_origin = StoredValue::new(target_origin);
+    let order_id = RwSignal::new(None::<String>);
+    let completion_posted = StoredValue::new(false);
+
+    let on_order_created = move |id: String| {
+        post(
+            &WidgetMessage::OrderCreated { id: &id },
+            &target_origin.read_value(),
+        );
+        order_id.set(Some(id));
+    };
+    let on_update = move |order: Order| {
+        if order.status == OrderStatus::Completed && !completion_posted.get_value() {
+            completion_posted.set_value(true);
+            post(
+                &WidgetMessage::OrderCompleted { order: &order },
+                &target_origin.read_value(),
+            );
+        }
+    };
+
+    view! {
+        <div class="bg-background text-foreground p-4" class:dark=theme == Theme::Dark>
+            {move || match order_id.get() {
+                None => view! {
+                    <UntronWidget initial=initial.get_value() fixed_recipient on_order_created />
+                }
+                .into_any(),
+                Some(id) => view! { <OrderTracker order_id=id on_update /> }.into_any(),
+            }}
+        </div>
+    }
+}
+
+fn post(message: &WidgetMessage, target_origin: &str) {
+    let json = serde_json::to_string(message).expect("message serializes");
+    let data = js_sys::JSON::parse(&json).expect("serde_json output is valid JSON");
+    let host = host_window().unwrap_or_else(window);
+    if let Err(err) = host.post_message(&data, target_origin) {
+        logging::warn!("posting {json} failed: {err:?}");
+    }
+}
+
+/// The parent window, when the widget is framed.
+fn host_window() -> Option<web_sys::Window> {
+    let window = window();
+    window
+        .parent()
+        .ok()
+        .flatten()
+        .filter(|parent| !js_sys::Object::is(parent, &window))
+}